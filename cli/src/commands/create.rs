@@ -4,7 +4,7 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{bail, Context, Result as AnyhowResult};
+use anyhow::{anyhow, bail, Context, Result as AnyhowResult};
 use mtly_reward_center::pda::find_reward_center_address;
 use mtly_reward_center_sdk::accounts::CreateRewardCenterAccounts;
 use mtly_reward_center_sdk::create_reward_center;
@@ -36,7 +36,7 @@ use spl_token::{instruction::initialize_mint, native_mint, state::Mint};
 
 use crate::{
     config::{parse_keypair, parse_solana_configuration},
-    schema::{CreateRewardCenterParams, PayoutOperation},
+    schema::{CreateRewardCenterParams, PayoutMode, PayoutOperation, RewardCurve},
 };
 
 #[must_use]
@@ -160,7 +160,9 @@ pub fn generate_rent_exempt_ixs(
     )
 }
 
-#[must_use]
+/// # Errors
+///
+/// Will return `Err` if the reward rules fail validation
 pub fn generate_create_reward_center_ix(
     wallet: Pubkey,
     rewards_mint: Pubkey,
@@ -169,32 +171,80 @@ pub fn generate_create_reward_center_ix(
         mathematical_operand,
         seller_reward_payout_basis_points,
         payout_numeral,
+        payout_mode,
+        attributor_reward_basis_points,
+        collection_oracle,
+        maker_taker_split,
+        reward_curve,
+        wash_trade_window_seconds,
+        min_order_age_seconds,
+        max_reward_per_sale,
+        max_reward_per_wallet_per_epoch,
     }: CreateRewardCenterParams,
-) -> Instruction {
-    create_reward_center(
+) -> AnyhowResult<Instruction> {
+    let collection_oracle = collection_oracle
+        .map(|collection_oracle| Pubkey::from_str(&collection_oracle))
+        .transpose()
+        .context("Failed to parse Pubkey from collection oracle string")?;
+
+    let reward_rules = mtly_reward_center::state::RewardRules {
+        seller_reward_payout_basis_points,
+        mathematical_operand: match mathematical_operand {
+            PayoutOperation::Divide => mtly_reward_center::state::PayoutOperation::Divide,
+            PayoutOperation::Multiple => mtly_reward_center::state::PayoutOperation::Multiple,
+        },
+        payout_numeral,
+        payout_mode: match payout_mode {
+            PayoutMode::Immediate => mtly_reward_center::state::PayoutMode::Immediate,
+            PayoutMode::Vested { cliff, duration } => {
+                mtly_reward_center::state::PayoutMode::Vested { cliff, duration }
+            },
+        },
+        attributor_reward_basis_points,
+        collection_oracle,
+        maker_taker_split: maker_taker_split.map(|split| {
+            mtly_reward_center::state::MakerTakerSplit {
+                maker_reward_basis_points: split.maker_reward_basis_points,
+                taker_reward_basis_points: split.taker_reward_basis_points,
+            }
+        }),
+        reward_curve: match reward_curve {
+            RewardCurve::Linear => mtly_reward_center::state::RewardCurve::Linear,
+            RewardCurve::Tiered(tiers) => mtly_reward_center::state::RewardCurve::Tiered(
+                tiers
+                    .into_iter()
+                    .map(|(price_threshold, numerator, denominator)| {
+                        mtly_reward_center::state::RewardTier {
+                            price_threshold,
+                            numerator,
+                            denominator,
+                        }
+                    })
+                    .collect(),
+            ),
+            RewardCurve::Capped { max_reward } => {
+                mtly_reward_center::state::RewardCurve::Capped { max_reward }
+            },
+        },
+        wash_trade_window_seconds,
+        min_order_age_seconds,
+        max_reward_per_sale,
+        max_reward_per_wallet_per_epoch,
+    };
+
+    reward_rules
+        .validate()
+        .map_err(|error| anyhow!("Invalid reward rules: {}", error))?;
+
+    Ok(create_reward_center(
         CreateRewardCenterAccounts {
             wallet,
             mint: rewards_mint,
             auction_house,
             auction_house_treasury_mint: native_mint::id(),
         },
-        mtly_reward_center::reward_centers::create::CreateRewardCenterParams {
-            reward_rules: {
-                mtly_reward_center::state::RewardRules {
-                    seller_reward_payout_basis_points,
-                    mathematical_operand: match mathematical_operand {
-                        PayoutOperation::Divide => {
-                            mtly_reward_center::state::PayoutOperation::Divide
-                        },
-                        PayoutOperation::Multiple => {
-                            mtly_reward_center::state::PayoutOperation::Multiple
-                        },
-                    },
-                    payout_numeral,
-                }
-            },
-        },
-    )
+        mtly_reward_center::reward_centers::create::CreateRewardCenterParams { reward_rules },
+    ))
 }
 
 /// # Errors
@@ -259,11 +309,7 @@ pub fn process_create_reward_center(
         serde_json::from_reader(create_reward_center_config_file)?
     } else {
         warn!("Create reward center config doesn't exist");
-        CreateRewardCenterParams {
-            mathematical_operand: PayoutOperation::Divide,
-            payout_numeral: 5,
-            seller_reward_payout_basis_points: 1000,
-        }
+        CreateRewardCenterParams::default()
     };
 
     let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
@@ -273,7 +319,7 @@ pub fn process_create_reward_center(
         rewards_mint_pubkey,
         auction_house_pubkey,
         create_reward_center_params,
-    );
+    )?;
 
     instructions.push(create_reward_center_ix);
 
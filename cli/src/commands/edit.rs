@@ -4,10 +4,10 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{bail, Context, Result as AnyhowResult};
+use anyhow::{anyhow, bail, Context, Result as AnyhowResult};
 use mtly_reward_center::{
     reward_centers::edit::EditRewardCenterParams,
-    state::{PayoutOperation, RewardRules},
+    state::{MakerTakerSplit, PayoutMode, PayoutOperation, RewardCurve, RewardRules, RewardTier},
 };
 use mtly_reward_center_sdk::edit_reward_center;
 use log::{error, info};
@@ -45,6 +45,11 @@ pub fn process_edit_reward_center(
         let create_reward_center_config_file = File::open(config_file)?;
         let edit_reward_center_config: crate::schema::EditRewardCenterParams =
             serde_json::from_reader(create_reward_center_config_file)?;
+        let collection_oracle = edit_reward_center_config
+            .collection_oracle
+            .map(|collection_oracle| Pubkey::from_str(&collection_oracle))
+            .transpose()
+            .context("Failed to parse Pubkey from collection oracle string")?;
 
         EditRewardCenterParams {
             reward_rules: RewardRules {
@@ -55,6 +60,42 @@ pub fn process_edit_reward_center(
                     crate::schema::PayoutOperation::Multiple => PayoutOperation::Multiple,
                 },
                 payout_numeral: edit_reward_center_config.payout_numeral,
+                payout_mode: match edit_reward_center_config.payout_mode {
+                    crate::schema::PayoutMode::Immediate => PayoutMode::Immediate,
+                    crate::schema::PayoutMode::Vested { cliff, duration } => {
+                        PayoutMode::Vested { cliff, duration }
+                    },
+                },
+                attributor_reward_basis_points: edit_reward_center_config
+                    .attributor_reward_basis_points,
+                collection_oracle,
+                maker_taker_split: edit_reward_center_config.maker_taker_split.map(|split| {
+                    MakerTakerSplit {
+                        maker_reward_basis_points: split.maker_reward_basis_points,
+                        taker_reward_basis_points: split.taker_reward_basis_points,
+                    }
+                }),
+                reward_curve: match edit_reward_center_config.reward_curve {
+                    crate::schema::RewardCurve::Linear => RewardCurve::Linear,
+                    crate::schema::RewardCurve::Tiered(tiers) => RewardCurve::Tiered(
+                        tiers
+                            .into_iter()
+                            .map(|(price_threshold, numerator, denominator)| RewardTier {
+                                price_threshold,
+                                numerator,
+                                denominator,
+                            })
+                            .collect(),
+                    ),
+                    crate::schema::RewardCurve::Capped { max_reward } => {
+                        RewardCurve::Capped { max_reward }
+                    },
+                },
+                wash_trade_window_seconds: edit_reward_center_config.wash_trade_window_seconds,
+                min_order_age_seconds: edit_reward_center_config.min_order_age_seconds,
+                max_reward_per_sale: edit_reward_center_config.max_reward_per_sale,
+                max_reward_per_wallet_per_epoch: edit_reward_center_config
+                    .max_reward_per_wallet_per_epoch,
             },
         }
     } else {
@@ -62,6 +103,11 @@ pub fn process_edit_reward_center(
         bail!("Update config missing")
     };
 
+    edit_reward_center_params
+        .reward_rules
+        .validate()
+        .map_err(|error| anyhow!("Invalid reward rules: {}", error))?;
+
     let edit_reward_center_ix = edit_reward_center(
         keypair.pubkey(),
         auction_house_pubkey,
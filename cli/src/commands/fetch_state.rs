@@ -21,14 +21,27 @@ pub fn process_fetch_reward_center_state(
         .context("Failed to get reward center data")?;
 
     let RewardCenter {
+        version,
         auction_house,
+        authority,
         reward_rules,
+        candidate_reward_rules,
+        reward_mode,
         token_mint,
+        treasury_mint_decimals,
+        reward_mint_decimals,
+        pause_state,
+        open_listings,
+        open_offers,
+        outstanding_rewards,
+        has_uncounted_obligations,
         ..
     } = RewardCenter::deserialize(&mut &reward_center_data[8..])?;
 
     info!("Reward Center address: {}", reward_center);
+    info!("Reward Center version: {}", version);
     info!("Auction house address: {}", auction_house.to_string());
+    info!("Reward Center authority: {}", authority.to_string());
     info!(
         "Reward Center rewards mint address: {}",
         token_mint.to_string()
@@ -45,6 +58,56 @@ pub fn process_fetch_reward_center_state(
         "Reward Center payout numeral: {}",
         reward_rules.payout_numeral
     );
+    info!(
+        "Reward Center attributor reward basis points: {}",
+        reward_rules.attributor_reward_basis_points
+    );
+    info!(
+        "Reward Center collection oracle: {:?}",
+        reward_rules.collection_oracle
+    );
+    info!(
+        "Reward Center maker taker split: {:?}",
+        reward_rules.maker_taker_split
+    );
+    info!("Reward Center reward curve: {:?}", reward_rules.reward_curve);
+    info!(
+        "Reward Center wash trade window seconds: {}",
+        reward_rules.wash_trade_window_seconds
+    );
+    info!(
+        "Reward Center min order age seconds: {}",
+        reward_rules.min_order_age_seconds
+    );
+    info!(
+        "Reward Center max reward per sale: {:?}",
+        reward_rules.max_reward_per_sale
+    );
+    info!(
+        "Reward Center max reward per wallet per epoch: {:?}",
+        reward_rules.max_reward_per_wallet_per_epoch
+    );
+    info!(
+        "Reward Center treasury mint decimals: {}",
+        treasury_mint_decimals
+    );
+    info!(
+        "Reward Center reward mint decimals: {}",
+        reward_mint_decimals
+    );
+    info!("Reward Center pause state: {:#06b}", pause_state);
+    info!("Reward Center reward mode: {:?}", reward_mode);
+    info!(
+        "Reward Center candidate reward rules: {:?}",
+        candidate_reward_rules
+    );
+    info!("Reward Center open listings: {}", open_listings);
+    info!("Reward Center open offers: {}", open_offers);
+    info!("Reward Center outstanding rewards: {}", outstanding_rewards);
+    info!(
+        "Reward Center has uncounted obligations: {}",
+        has_uncounted_obligations
+    );
 
     Ok(())
 }
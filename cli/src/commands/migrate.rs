@@ -0,0 +1,234 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::{Context, Result as AnyhowResult};
+use log::info;
+use mtly_auction_house::AuctionHouse;
+use mtly_reward_center::{
+    id,
+    state::{Listing, Offer, RewardCenter},
+};
+use mtly_reward_center_sdk::{accounts::MigrateRewardCenterAccounts, migrate_reward_center};
+use retry::{delay::Exponential, retry};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::config::{parse_keypair, parse_solana_configuration};
+
+/// Number of listings and offers upgraded per transaction
+const MIGRATION_BATCH_SIZE: usize = 20;
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Reward center address fails to parse
+/// 2. Reward center, program or auction house data fails to be fetched
+/// 3. A migration transaction fails
+pub fn process_migrate_reward_center(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    reward_center: &Option<String>,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let reward_centers = if let Some(reward_center) = reward_center {
+        let reward_center_pubkey = Pubkey::from_str(reward_center)
+            .context("Failed to parse Pubkey from reward center string")?;
+
+        info!("Getting reward center data");
+        let reward_center_data = client
+            .get_account_data(&reward_center_pubkey)
+            .context("Failed to get reward center data")?;
+
+        vec![(reward_center_pubkey, reward_center_data)]
+    } else {
+        info!("Searching for reward centers");
+        find_reward_centers(client)?
+    };
+
+    info!("Found {} reward centers", reward_centers.len());
+
+    for (reward_center_pubkey, reward_center_data) in reward_centers {
+        migrate_reward_center_accounts(
+            client,
+            &keypair,
+            &reward_center_pubkey,
+            &reward_center_data,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Upgrades the reward center along with its outdated listings and offers, skipping reward centers
+/// already at the current version without any outdated listing or offer.
+fn migrate_reward_center_accounts(
+    client: &RpcClient,
+    keypair: &Keypair,
+    reward_center_pubkey: &Pubkey,
+    reward_center_data: &[u8],
+) -> AnyhowResult<()> {
+    // Accounts created before versioning lack the leading version byte
+    let version_offset = usize::from(reward_center_data.len() >= RewardCenter::size());
+    let token_mint =
+        Pubkey::try_from_slice(&reward_center_data[8 + version_offset..40 + version_offset])?;
+    let auction_house =
+        Pubkey::try_from_slice(&reward_center_data[40 + version_offset..72 + version_offset])?;
+    let is_current_version = version_offset == 1 && reward_center_data[8] == RewardCenter::VERSION;
+
+    info!(
+        "Searching for outdated listings and offers of {}",
+        reward_center_pubkey
+    );
+    let mut outdated_accounts = find_outdated_accounts(
+        client,
+        reward_center_pubkey,
+        Listing::discriminator(),
+        Listing::size(),
+        Listing::VERSION,
+    )?;
+    outdated_accounts.extend(find_outdated_accounts(
+        client,
+        reward_center_pubkey,
+        Offer::discriminator(),
+        Offer::size(),
+        Offer::VERSION,
+    )?);
+
+    if is_current_version && outdated_accounts.is_empty() {
+        info!(
+            "Reward center {} is already at version {}",
+            reward_center_pubkey,
+            RewardCenter::VERSION
+        );
+
+        return Ok(());
+    }
+
+    info!(
+        "Found {} listings and offers to upgrade",
+        outdated_accounts.len()
+    );
+
+    info!("Getting auction house data");
+    let auction_house_data = client
+        .get_account_data(&auction_house)
+        .context("Failed to get auction house data")?;
+    let AuctionHouse { treasury_mint, .. } =
+        AuctionHouse::deserialize(&mut &auction_house_data[8..])?;
+
+    let mut batches: Vec<&[Pubkey]> = outdated_accounts.chunks(MIGRATION_BATCH_SIZE).collect();
+    if batches.is_empty() {
+        batches.push(&[]);
+    }
+
+    for batch in batches {
+        let instruction = migrate_reward_center(
+            MigrateRewardCenterAccounts {
+                payer: keypair.pubkey(),
+                auction_house,
+                treasury_mint,
+                rewards_mint: token_mint,
+            },
+            batch,
+        );
+
+        let latest_blockhash = client.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            latest_blockhash,
+        );
+
+        let tx_hash = retry(
+            Exponential::from_millis_with_factor(250, 2.0).take(3),
+            || client.send_and_confirm_transaction(&transaction),
+        )?;
+
+        info!(
+            "Upgraded {} listings and offers. Tx hash {}",
+            batch.len(),
+            tx_hash
+        );
+    }
+
+    Ok(())
+}
+
+/// Finds every reward center of the program, whatever the version of its layout.
+fn find_reward_centers(client: &RpcClient) -> AnyhowResult<Vec<(Pubkey, Vec<u8>)>> {
+    let accounts = client
+        .get_program_accounts_with_config(
+            &id(),
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                    0,
+                    &RewardCenter::discriminator(),
+                ))]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            },
+        )
+        .context("Failed to get program accounts")?;
+
+    Ok(accounts
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey, account.data))
+        .collect())
+}
+
+/// Finds the accounts of the given type belonging to the reward center that are below the current
+/// version, including those still using the unversioned layout where the reward center
+/// immediately follows the discriminator.
+fn find_outdated_accounts(
+    client: &RpcClient,
+    reward_center: &Pubkey,
+    discriminator: [u8; 8],
+    size: usize,
+    version: u8,
+) -> AnyhowResult<Vec<Pubkey>> {
+    let mut outdated_accounts = Vec::new();
+
+    for reward_center_offset in [8, 9] {
+        let accounts = client
+            .get_program_accounts_with_config(
+                &id(),
+                RpcProgramAccountsConfig {
+                    filters: Some(vec![
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &discriminator)),
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                            reward_center_offset,
+                            reward_center.as_ref(),
+                        )),
+                    ]),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                },
+            )
+            .context("Failed to get program accounts")?;
+
+        outdated_accounts.extend(
+            accounts
+                .into_iter()
+                .filter(|(_, account)| account.data.len() < size || account.data[8] < version)
+                .map(|(pubkey, _)| pubkey),
+        );
+    }
+
+    Ok(outdated_accounts)
+}
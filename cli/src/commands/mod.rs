@@ -4,6 +4,7 @@ pub mod edit;
 pub mod fetch_balance;
 pub mod fetch_state;
 pub mod fund;
+pub mod migrate;
 pub mod withdraw_auction_house;
 pub mod withdraw_reward_center;
 
@@ -13,5 +14,6 @@ pub use edit::*;
 pub use fetch_balance::*;
 pub use fetch_state::*;
 pub use fund::*;
+pub use migrate::*;
 pub use withdraw_auction_house::*;
 pub use withdraw_reward_center::*;
@@ -17,7 +17,8 @@ use reward_center_cli::{
         process_create_address_table_lookup, process_create_reward_center,
         process_edit_reward_center, process_fetch_reward_center_state,
         process_fetch_reward_center_treasury_balance, process_fund_reward_center,
-        process_withdraw_auction_house_treasury, process_withdraw_reward_center_treasury,
+        process_migrate_reward_center, process_withdraw_auction_house_treasury,
+        process_withdraw_reward_center_treasury,
     },
     config::parse_solana_configuration,
     constants::PUBLIC_RPC_URLS,
@@ -117,6 +118,11 @@ fn run() -> Result<()> {
             process_fetch_reward_center_treasury_balance(&client, &reward_center)?;
         },
 
+        Command::Migrate {
+            reward_center,
+            keypair,
+        } => process_migrate_reward_center(&client, &keypair, &reward_center)?,
+
         Command::WithdrawAuctionHouse {
             auction_house,
             keypair,
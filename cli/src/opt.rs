@@ -115,6 +115,18 @@ pub enum Command {
         keypair: Option<PathBuf>,
     },
 
+    /// Upgrade reward centers, their listings and their offers to the current account layout
+    #[clap(name = "migrate")]
+    Migrate {
+        /// Reward center address, every reward center of the program when omitted
+        #[arg(short = 'R', long)]
+        reward_center: Option<String>,
+
+        /// Path to the keypair file paying for the upgraded accounts
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
     /// Withdraw from Reward center treasury
     #[clap(name = "withdraw-reward-center")]
     WithdrawRewardCenter {
@@ -6,16 +6,92 @@ pub enum PayoutOperation {
     Divide,
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum PayoutMode {
+    #[default]
+    Immediate,
+    Vested { cliff: i64, duration: i64 },
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub enum RewardCurve {
+    #[default]
+    Linear,
+    Tiered(Vec<(u64, u16, u16)>),
+    Capped { max_reward: u64 },
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct MakerTakerSplit {
+    pub maker_reward_basis_points: u16,
+    pub taker_reward_basis_points: u16,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateRewardCenterParams {
     pub mathematical_operand: PayoutOperation,
     pub seller_reward_payout_basis_points: u16,
     pub payout_numeral: u16,
+    #[serde(default)]
+    pub payout_mode: PayoutMode,
+    #[serde(default)]
+    pub attributor_reward_basis_points: u16,
+    #[serde(default)]
+    pub collection_oracle: Option<String>,
+    #[serde(default)]
+    pub maker_taker_split: Option<MakerTakerSplit>,
+    #[serde(default)]
+    pub reward_curve: RewardCurve,
+    #[serde(default)]
+    pub wash_trade_window_seconds: i64,
+    #[serde(default)]
+    pub min_order_age_seconds: i64,
+    #[serde(default)]
+    pub max_reward_per_sale: Option<u64>,
+    #[serde(default)]
+    pub max_reward_per_wallet_per_epoch: Option<u64>,
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+impl Default for CreateRewardCenterParams {
+    fn default() -> Self {
+        Self {
+            mathematical_operand: PayoutOperation::Divide,
+            seller_reward_payout_basis_points: 1000,
+            payout_numeral: 5,
+            payout_mode: PayoutMode::default(),
+            attributor_reward_basis_points: 0,
+            collection_oracle: None,
+            maker_taker_split: None,
+            reward_curve: RewardCurve::default(),
+            wash_trade_window_seconds: 0,
+            min_order_age_seconds: 0,
+            max_reward_per_sale: None,
+            max_reward_per_wallet_per_epoch: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EditRewardCenterParams {
     pub mathematical_operand: PayoutOperation,
     pub seller_reward_payout_basis_points: u16,
     pub payout_numeral: u16,
+    #[serde(default)]
+    pub payout_mode: PayoutMode,
+    #[serde(default)]
+    pub attributor_reward_basis_points: u16,
+    #[serde(default)]
+    pub collection_oracle: Option<String>,
+    #[serde(default)]
+    pub maker_taker_split: Option<MakerTakerSplit>,
+    #[serde(default)]
+    pub reward_curve: RewardCurve,
+    #[serde(default)]
+    pub wash_trade_window_seconds: i64,
+    #[serde(default)]
+    pub min_order_age_seconds: i64,
+    #[serde(default)]
+    pub max_reward_per_sale: Option<u64>,
+    #[serde(default)]
+    pub max_reward_per_wallet_per_epoch: Option<u64>,
 }
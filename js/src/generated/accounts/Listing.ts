@@ -15,6 +15,7 @@ import * as beetSolana from '@metaplex-foundation/beet-solana';
  * @category generated
  */
 export type ListingArgs = {
+  version: number;
   rewardCenter: web3.PublicKey;
   seller: web3.PublicKey;
   metadata: web3.PublicKey;
@@ -22,6 +23,9 @@ export type ListingArgs = {
   tokenSize: beet.bignum;
   bump: number;
   createdAt: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
+  updatedAt: beet.bignum;
+  reserved: number[] /* size: 24 */;
 };
 
 export const listingDiscriminator = [218, 32, 50, 73, 43, 134, 26, 58];
@@ -34,6 +38,7 @@ export const listingDiscriminator = [218, 32, 50, 73, 43, 134, 26, 58];
  */
 export class Listing implements ListingArgs {
   private constructor(
+    readonly version: number,
     readonly rewardCenter: web3.PublicKey,
     readonly seller: web3.PublicKey,
     readonly metadata: web3.PublicKey,
@@ -41,6 +46,9 @@ export class Listing implements ListingArgs {
     readonly tokenSize: beet.bignum,
     readonly bump: number,
     readonly createdAt: beet.bignum,
+    readonly expiresAt: beet.COption<beet.bignum>,
+    readonly updatedAt: beet.bignum,
+    readonly reserved: number[] /* size: 24 */,
   ) {}
 
   /**
@@ -48,6 +56,7 @@ export class Listing implements ListingArgs {
    */
   static fromArgs(args: ListingArgs) {
     return new Listing(
+      args.version,
       args.rewardCenter,
       args.seller,
       args.metadata,
@@ -55,6 +64,9 @@ export class Listing implements ListingArgs {
       args.tokenSize,
       args.bump,
       args.createdAt,
+      args.expiresAt,
+      args.updatedAt,
+      args.reserved,
     );
   }
 
@@ -117,31 +129,33 @@ export class Listing implements ListingArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Listing}
+   * {@link Listing} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return listingBeet.byteSize;
+  static byteSize(args: ListingArgs) {
+    const instance = Listing.fromArgs(args);
+    return listingBeet.toFixedFromValue({
+      accountDiscriminator: listingDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Listing} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ListingArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(Listing.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Listing} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Listing.byteSize;
+    return connection.getMinimumBalanceForRentExemption(Listing.byteSize(args), commitment);
   }
 
   /**
@@ -150,6 +164,7 @@ export class Listing implements ListingArgs {
    */
   pretty() {
     return {
+      version: this.version,
       rewardCenter: this.rewardCenter.toBase58(),
       seller: this.seller.toBase58(),
       metadata: this.metadata.toBase58(),
@@ -187,6 +202,19 @@ export class Listing implements ListingArgs {
         }
         return x;
       })(),
+      expiresAt: this.expiresAt,
+      updatedAt: (() => {
+        const x = <{ toNumber: () => number }>this.updatedAt;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      reserved: this.reserved,
     };
   }
 }
@@ -195,7 +223,7 @@ export class Listing implements ListingArgs {
  * @category Accounts
  * @category generated
  */
-export const listingBeet = new beet.BeetStruct<
+export const listingBeet = new beet.FixableBeetStruct<
   Listing,
   ListingArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -203,6 +231,7 @@ export const listingBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['rewardCenter', beetSolana.publicKey],
     ['seller', beetSolana.publicKey],
     ['metadata', beetSolana.publicKey],
@@ -210,6 +239,9 @@ export const listingBeet = new beet.BeetStruct<
     ['tokenSize', beet.u64],
     ['bump', beet.u8],
     ['createdAt', beet.i64],
+    ['expiresAt', beet.coption(beet.i64)],
+    ['updatedAt', beet.i64],
+    ['reserved', beet.uniformFixedSizeArray(beet.u8, 24)],
   ],
   Listing.fromArgs,
   'Listing',
@@ -15,6 +15,7 @@ import * as beetSolana from '@metaplex-foundation/beet-solana';
  * @category generated
  */
 export type OfferArgs = {
+  version: number;
   rewardCenter: web3.PublicKey;
   buyer: web3.PublicKey;
   metadata: web3.PublicKey;
@@ -23,6 +24,9 @@ export type OfferArgs = {
   bump: number;
   createdAt: beet.bignum;
   priceWithFees: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
+  updatedAt: beet.bignum;
+  reserved: number[] /* size: 24 */;
 };
 
 export const offerDiscriminator = [215, 88, 60, 71, 170, 162, 73, 229];
@@ -35,6 +39,7 @@ export const offerDiscriminator = [215, 88, 60, 71, 170, 162, 73, 229];
  */
 export class Offer implements OfferArgs {
   private constructor(
+    readonly version: number,
     readonly rewardCenter: web3.PublicKey,
     readonly buyer: web3.PublicKey,
     readonly metadata: web3.PublicKey,
@@ -43,6 +48,9 @@ export class Offer implements OfferArgs {
     readonly bump: number,
     readonly createdAt: beet.bignum,
     readonly priceWithFees: beet.bignum,
+    readonly expiresAt: beet.COption<beet.bignum>,
+    readonly updatedAt: beet.bignum,
+    readonly reserved: number[] /* size: 24 */,
   ) {}
 
   /**
@@ -50,6 +58,7 @@ export class Offer implements OfferArgs {
    */
   static fromArgs(args: OfferArgs) {
     return new Offer(
+      args.version,
       args.rewardCenter,
       args.buyer,
       args.metadata,
@@ -58,6 +67,9 @@ export class Offer implements OfferArgs {
       args.bump,
       args.createdAt,
       args.priceWithFees,
+      args.expiresAt,
+      args.updatedAt,
+      args.reserved,
     );
   }
 
@@ -120,31 +132,33 @@ export class Offer implements OfferArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Offer}
+   * {@link Offer} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return offerBeet.byteSize;
+  static byteSize(args: OfferArgs) {
+    const instance = Offer.fromArgs(args);
+    return offerBeet.toFixedFromValue({
+      accountDiscriminator: offerDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Offer} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: OfferArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(Offer.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Offer} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Offer.byteSize;
+    return connection.getMinimumBalanceForRentExemption(Offer.byteSize(args), commitment);
   }
 
   /**
@@ -153,6 +167,7 @@ export class Offer implements OfferArgs {
    */
   pretty() {
     return {
+      version: this.version,
       rewardCenter: this.rewardCenter.toBase58(),
       buyer: this.buyer.toBase58(),
       metadata: this.metadata.toBase58(),
@@ -201,6 +216,19 @@ export class Offer implements OfferArgs {
         }
         return x;
       })(),
+      expiresAt: this.expiresAt,
+      updatedAt: (() => {
+        const x = <{ toNumber: () => number }>this.updatedAt;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      reserved: this.reserved,
     };
   }
 }
@@ -209,7 +237,7 @@ export class Offer implements OfferArgs {
  * @category Accounts
  * @category generated
  */
-export const offerBeet = new beet.BeetStruct<
+export const offerBeet = new beet.FixableBeetStruct<
   Offer,
   OfferArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -217,6 +245,7 @@ export const offerBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['rewardCenter', beetSolana.publicKey],
     ['buyer', beetSolana.publicKey],
     ['metadata', beetSolana.publicKey],
@@ -225,6 +254,9 @@ export const offerBeet = new beet.BeetStruct<
     ['bump', beet.u8],
     ['createdAt', beet.i64],
     ['priceWithFees', beet.u64],
+    ['expiresAt', beet.coption(beet.i64)],
+    ['updatedAt', beet.i64],
+    ['reserved', beet.uniformFixedSizeArray(beet.u8, 24)],
   ],
   Offer.fromArgs,
   'Offer',
@@ -6,9 +6,10 @@
  */
 
 import * as web3 from '@solana/web3.js';
-import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { RewardRules, rewardRulesBeet } from '../types/RewardRules';
+import { RewardMode, rewardModeBeet } from '../types/RewardMode';
 
 /**
  * Arguments used to create {@link RewardCenter}
@@ -16,10 +17,23 @@ import { RewardRules, rewardRulesBeet } from '../types/RewardRules';
  * @category generated
  */
 export type RewardCenterArgs = {
+  version: number;
   tokenMint: web3.PublicKey;
   auctionHouse: web3.PublicKey;
-  rewardRules: RewardRules;
+  authority: web3.PublicKey;
+  pendingAuthority: beet.COption<web3.PublicKey>;
+  treasuryMintDecimals: number;
+  rewardMintDecimals: number;
+  pauseState: number;
+  openListings: beet.bignum;
+  openOffers: beet.bignum;
   bump: number;
+  rewardRules: RewardRules;
+  candidateRewardRules: beet.COption<RewardRules>;
+  rewardMode: RewardMode;
+  outstandingRewards: beet.bignum;
+  hasUncountedObligations: boolean;
+  reserved: number[] /* size: 64 */;
 };
 
 export const rewardCenterDiscriminator = [28, 31, 56, 90, 176, 54, 120, 105];
@@ -32,17 +46,48 @@ export const rewardCenterDiscriminator = [28, 31, 56, 90, 176, 54, 120, 105];
  */
 export class RewardCenter implements RewardCenterArgs {
   private constructor(
+    readonly version: number,
     readonly tokenMint: web3.PublicKey,
     readonly auctionHouse: web3.PublicKey,
-    readonly rewardRules: RewardRules,
+    readonly authority: web3.PublicKey,
+    readonly pendingAuthority: beet.COption<web3.PublicKey>,
+    readonly treasuryMintDecimals: number,
+    readonly rewardMintDecimals: number,
+    readonly pauseState: number,
+    readonly openListings: beet.bignum,
+    readonly openOffers: beet.bignum,
     readonly bump: number,
+    readonly rewardRules: RewardRules,
+    readonly candidateRewardRules: beet.COption<RewardRules>,
+    readonly rewardMode: RewardMode,
+    readonly outstandingRewards: beet.bignum,
+    readonly hasUncountedObligations: boolean,
+    readonly reserved: number[] /* size: 64 */,
   ) {}
 
   /**
    * Creates a {@link RewardCenter} instance from the provided args.
    */
   static fromArgs(args: RewardCenterArgs) {
-    return new RewardCenter(args.tokenMint, args.auctionHouse, args.rewardRules, args.bump);
+    return new RewardCenter(
+      args.version,
+      args.tokenMint,
+      args.auctionHouse,
+      args.authority,
+      args.pendingAuthority,
+      args.treasuryMintDecimals,
+      args.rewardMintDecimals,
+      args.pauseState,
+      args.openListings,
+      args.openOffers,
+      args.bump,
+      args.rewardRules,
+      args.candidateRewardRules,
+      args.rewardMode,
+      args.outstandingRewards,
+      args.hasUncountedObligations,
+      args.reserved,
+    );
   }
 
   /**
@@ -107,31 +152,33 @@ export class RewardCenter implements RewardCenterArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link RewardCenter}
+   * {@link RewardCenter} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return rewardCenterBeet.byteSize;
+  static byteSize(args: RewardCenterArgs) {
+    const instance = RewardCenter.fromArgs(args);
+    return rewardCenterBeet.toFixedFromValue({
+      accountDiscriminator: rewardCenterDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link RewardCenter} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: RewardCenterArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(RewardCenter.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link RewardCenter} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === RewardCenter.byteSize;
+    return connection.getMinimumBalanceForRentExemption(RewardCenter.byteSize(args), commitment);
   }
 
   /**
@@ -140,10 +187,53 @@ export class RewardCenter implements RewardCenterArgs {
    */
   pretty() {
     return {
+      version: this.version,
       tokenMint: this.tokenMint.toBase58(),
       auctionHouse: this.auctionHouse.toBase58(),
-      rewardRules: this.rewardRules,
+      authority: this.authority.toBase58(),
+      pendingAuthority: this.pendingAuthority,
+      treasuryMintDecimals: this.treasuryMintDecimals,
+      rewardMintDecimals: this.rewardMintDecimals,
+      pauseState: this.pauseState,
+      openListings: (() => {
+        const x = <{ toNumber: () => number }>this.openListings;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      openOffers: (() => {
+        const x = <{ toNumber: () => number }>this.openOffers;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
       bump: this.bump,
+      rewardRules: this.rewardRules,
+      candidateRewardRules: this.candidateRewardRules,
+      rewardMode: 'RewardMode.' + RewardMode[this.rewardMode],
+      outstandingRewards: (() => {
+        const x = <{ toNumber: () => number }>this.outstandingRewards;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      hasUncountedObligations: this.hasUncountedObligations,
+      reserved: this.reserved,
     };
   }
 }
@@ -152,7 +242,7 @@ export class RewardCenter implements RewardCenterArgs {
  * @category Accounts
  * @category generated
  */
-export const rewardCenterBeet = new beet.BeetStruct<
+export const rewardCenterBeet = new beet.FixableBeetStruct<
   RewardCenter,
   RewardCenterArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -160,10 +250,23 @@ export const rewardCenterBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['tokenMint', beetSolana.publicKey],
     ['auctionHouse', beetSolana.publicKey],
-    ['rewardRules', rewardRulesBeet],
+    ['authority', beetSolana.publicKey],
+    ['pendingAuthority', beet.coption(beetSolana.publicKey)],
+    ['treasuryMintDecimals', beet.u8],
+    ['rewardMintDecimals', beet.u8],
+    ['pauseState', beet.u8],
+    ['openListings', beet.u64],
+    ['openOffers', beet.u64],
     ['bump', beet.u8],
+    ['rewardRules', rewardRulesBeet],
+    ['candidateRewardRules', beet.coption(rewardRulesBeet)],
+    ['rewardMode', rewardModeBeet],
+    ['outstandingRewards', beet.u64],
+    ['hasUncountedObligations', beet.bool],
+    ['reserved', beet.uniformFixedSizeArray(beet.u8, 64)],
   ],
   RewardCenter.fromArgs,
   'RewardCenter',
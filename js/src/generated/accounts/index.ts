@@ -1,9 +1,40 @@
+export * from './Attributor';
 export * from './Listing';
 export * from './Offer';
+export * from './PurchaseTicket';
 export * from './RewardCenter';
+export * from './RewardDebt';
+export * from './RewardVesting';
+export * from './RewardableCollection';
+export * from './SaleCounter';
+export * from './SaleRecord';
+export * from './SponsorPool';
+export * from './WalletRewardWindow';
 
 import { RewardCenter } from './RewardCenter';
 import { Listing } from './Listing';
 import { Offer } from './Offer';
+import { PurchaseTicket } from './PurchaseTicket';
+import { SaleCounter } from './SaleCounter';
+import { SaleRecord } from './SaleRecord';
+import { WalletRewardWindow } from './WalletRewardWindow';
+import { RewardableCollection } from './RewardableCollection';
+import { Attributor } from './Attributor';
+import { SponsorPool } from './SponsorPool';
+import { RewardDebt } from './RewardDebt';
+import { RewardVesting } from './RewardVesting';
 
-export const accountProviders = { RewardCenter, Listing, Offer };
+export const accountProviders = {
+  RewardCenter,
+  Listing,
+  Offer,
+  PurchaseTicket,
+  SaleCounter,
+  SaleRecord,
+  WalletRewardWindow,
+  RewardableCollection,
+  Attributor,
+  SponsorPool,
+  RewardDebt,
+  RewardVesting,
+};
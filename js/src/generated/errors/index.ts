@@ -285,6 +285,869 @@ export class PriceInvalidError extends Error {
 createErrorFromCodeLookup.set(0x177c, () => new PriceInvalidError());
 createErrorFromNameLookup.set('PriceInvalid', () => new PriceInvalidError());
 
+/**
+ * PriceExceedsMaximum: 'The listing price exceeds the maximum price set by the buyer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PriceExceedsMaximumError extends Error {
+  readonly code: number = 0x177d;
+  readonly name: string = 'PriceExceedsMaximum';
+  constructor() {
+    super('The listing price exceeds the maximum price set by the buyer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceExceedsMaximumError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177d, () => new PriceExceedsMaximumError());
+createErrorFromNameLookup.set('PriceExceedsMaximum', () => new PriceExceedsMaximumError());
+
+/**
+ * PriceBelowMinimum: 'The offer price is below the minimum price set by the seller'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PriceBelowMinimumError extends Error {
+  readonly code: number = 0x177e;
+  readonly name: string = 'PriceBelowMinimum';
+  constructor() {
+    super('The offer price is below the minimum price set by the seller');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceBelowMinimumError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177e, () => new PriceBelowMinimumError());
+createErrorFromNameLookup.set('PriceBelowMinimum', () => new PriceBelowMinimumError());
+
+/**
+ * ExpiryInvalid: 'The expiry must be in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExpiryInvalidError extends Error {
+  readonly code: number = 0x177f;
+  readonly name: string = 'ExpiryInvalid';
+  constructor() {
+    super('The expiry must be in the future');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExpiryInvalidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new ExpiryInvalidError());
+createErrorFromNameLookup.set('ExpiryInvalid', () => new ExpiryInvalidError());
+
+/**
+ * ListingExpired: 'The listing has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingExpiredError extends Error {
+  readonly code: number = 0x1780;
+  readonly name: string = 'ListingExpired';
+  constructor() {
+    super('The listing has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new ListingExpiredError());
+createErrorFromNameLookup.set('ListingExpired', () => new ListingExpiredError());
+
+/**
+ * ListingNotExpired: 'The listing has not expired yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingNotExpiredError extends Error {
+  readonly code: number = 0x1781;
+  readonly name: string = 'ListingNotExpired';
+  constructor() {
+    super('The listing has not expired yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new ListingNotExpiredError());
+createErrorFromNameLookup.set('ListingNotExpired', () => new ListingNotExpiredError());
+
+/**
+ * OfferExpired: 'The offer has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferExpiredError extends Error {
+  readonly code: number = 0x1782;
+  readonly name: string = 'OfferExpired';
+  constructor() {
+    super('The offer has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new OfferExpiredError());
+createErrorFromNameLookup.set('OfferExpired', () => new OfferExpiredError());
+
+/**
+ * OfferNotExpired: 'The offer has not expired yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferNotExpiredError extends Error {
+  readonly code: number = 0x1783;
+  readonly name: string = 'OfferNotExpired';
+  constructor() {
+    super('The offer has not expired yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new OfferNotExpiredError());
+createErrorFromNameLookup.set('OfferNotExpired', () => new OfferNotExpiredError());
+
+/**
+ * RewardDebtMismatch: 'The reward debt account does not belong to the wallet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardDebtMismatchError extends Error {
+  readonly code: number = 0x1784;
+  readonly name: string = 'RewardDebtMismatch';
+  constructor() {
+    super('The reward debt account does not belong to the wallet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardDebtMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new RewardDebtMismatchError());
+createErrorFromNameLookup.set('RewardDebtMismatch', () => new RewardDebtMismatchError());
+
+/**
+ * RewardVestingMismatch: 'The reward vesting account does not belong to the wallet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardVestingMismatchError extends Error {
+  readonly code: number = 0x1785;
+  readonly name: string = 'RewardVestingMismatch';
+  constructor() {
+    super('The reward vesting account does not belong to the wallet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardVestingMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new RewardVestingMismatchError());
+createErrorFromNameLookup.set('RewardVestingMismatch', () => new RewardVestingMismatchError());
+
+/**
+ * NoVestedRewards: 'There are no vested rewards to claim'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoVestedRewardsError extends Error {
+  readonly code: number = 0x1786;
+  readonly name: string = 'NoVestedRewards';
+  constructor() {
+    super('There are no vested rewards to claim');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoVestedRewardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new NoVestedRewardsError());
+createErrorFromNameLookup.set('NoVestedRewards', () => new NoVestedRewardsError());
+
+/**
+ * InvalidSellerRewardPayoutBasisPoints: 'Seller reward payout basis points cannot exceed 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSellerRewardPayoutBasisPointsError extends Error {
+  readonly code: number = 0x1787;
+  readonly name: string = 'InvalidSellerRewardPayoutBasisPoints';
+  constructor() {
+    super('Seller reward payout basis points cannot exceed 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSellerRewardPayoutBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new InvalidSellerRewardPayoutBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidSellerRewardPayoutBasisPoints',
+  () => new InvalidSellerRewardPayoutBasisPointsError(),
+);
+
+/**
+ * InvalidPayoutNumeral: 'Payout numeral must be greater than zero when dividing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPayoutNumeralError extends Error {
+  readonly code: number = 0x1788;
+  readonly name: string = 'InvalidPayoutNumeral';
+  constructor() {
+    super('Payout numeral must be greater than zero when dividing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPayoutNumeralError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new InvalidPayoutNumeralError());
+createErrorFromNameLookup.set('InvalidPayoutNumeral', () => new InvalidPayoutNumeralError());
+
+/**
+ * InvalidVestingSchedule: 'Vesting cliff and duration must be non-negative and the cliff cannot exceed the duration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidVestingScheduleError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = 'InvalidVestingSchedule';
+  constructor() {
+    super(
+      'Vesting cliff and duration must be non-negative and the cliff cannot exceed the duration',
+    );
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidVestingScheduleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new InvalidVestingScheduleError());
+createErrorFromNameLookup.set('InvalidVestingSchedule', () => new InvalidVestingScheduleError());
+
+/**
+ * PendingAuthorityMismatch: 'The signer is not the proposed reward center authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PendingAuthorityMismatchError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = 'PendingAuthorityMismatch';
+  constructor() {
+    super('The signer is not the proposed reward center authority');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PendingAuthorityMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new PendingAuthorityMismatchError());
+createErrorFromNameLookup.set(
+  'PendingAuthorityMismatch',
+  () => new PendingAuthorityMismatchError(),
+);
+
+/**
+ * ListingsPaused: 'Listings are paused for this reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingsPausedError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = 'ListingsPaused';
+  constructor() {
+    super('Listings are paused for this reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingsPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new ListingsPausedError());
+createErrorFromNameLookup.set('ListingsPaused', () => new ListingsPausedError());
+
+/**
+ * OffersPaused: 'Offers are paused for this reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OffersPausedError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = 'OffersPaused';
+  constructor() {
+    super('Offers are paused for this reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OffersPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new OffersPausedError());
+createErrorFromNameLookup.set('OffersPaused', () => new OffersPausedError());
+
+/**
+ * SalesPaused: 'Sales are paused for this reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SalesPausedError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = 'SalesPaused';
+  constructor() {
+    super('Sales are paused for this reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SalesPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new SalesPausedError());
+createErrorFromNameLookup.set('SalesPaused', () => new SalesPausedError());
+
+/**
+ * RewardsPaused: 'Rewards are paused for this reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardsPausedError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = 'RewardsPaused';
+  constructor() {
+    super('Rewards are paused for this reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardsPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new RewardsPausedError());
+createErrorFromNameLookup.set('RewardsPaused', () => new RewardsPausedError());
+
+/**
+ * InvalidPauseState: 'The pause state contains unknown flags'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPauseStateError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = 'InvalidPauseState';
+  constructor() {
+    super('The pause state contains unknown flags');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPauseStateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new InvalidPauseStateError());
+createErrorFromNameLookup.set('InvalidPauseState', () => new InvalidPauseStateError());
+
+/**
+ * RewardCenterHasOpenOrders: 'The reward center still has open listings or offers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCenterHasOpenOrdersError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = 'RewardCenterHasOpenOrders';
+  constructor() {
+    super('The reward center still has open listings or offers');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCenterHasOpenOrdersError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new RewardCenterHasOpenOrdersError());
+createErrorFromNameLookup.set(
+  'RewardCenterHasOpenOrders',
+  () => new RewardCenterHasOpenOrdersError(),
+);
+
+/**
+ * InvalidMigrationAccount: 'Account is not a listing or offer of the reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMigrationAccountError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = 'InvalidMigrationAccount';
+  constructor() {
+    super('Account is not a listing or offer of the reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMigrationAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new InvalidMigrationAccountError());
+createErrorFromNameLookup.set('InvalidMigrationAccount', () => new InvalidMigrationAccountError());
+
+/**
+ * PurchaseTicketMismatch: 'Purchase ticket does not match the sale'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PurchaseTicketMismatchError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'PurchaseTicketMismatch';
+  constructor() {
+    super('Purchase ticket does not match the sale');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PurchaseTicketMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new PurchaseTicketMismatchError());
+createErrorFromNameLookup.set('PurchaseTicketMismatch', () => new PurchaseTicketMismatchError());
+
+/**
+ * InvalidAttributorRewardBasisPoints: 'Attributor reward basis points must not exceed 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAttributorRewardBasisPointsError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'InvalidAttributorRewardBasisPoints';
+  constructor() {
+    super('Attributor reward basis points must not exceed 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAttributorRewardBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new InvalidAttributorRewardBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidAttributorRewardBasisPoints',
+  () => new InvalidAttributorRewardBasisPointsError(),
+);
+
+/**
+ * AttributorMismatch: 'Attributor and its reward token account must be passed together'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AttributorMismatchError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'AttributorMismatch';
+  constructor() {
+    super('Attributor and its reward token account must be passed together');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AttributorMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new AttributorMismatchError());
+createErrorFromNameLookup.set('AttributorMismatch', () => new AttributorMismatchError());
+
+/**
+ * AttributionMemoTooLong: 'Attribution memo is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AttributionMemoTooLongError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'AttributionMemoTooLong';
+  constructor() {
+    super('Attribution memo is too long');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AttributionMemoTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new AttributionMemoTooLongError());
+createErrorFromNameLookup.set('AttributionMemoTooLong', () => new AttributionMemoTooLongError());
+
+/**
+ * InvalidMakerTakerBasisPoints: 'Maker and taker reward basis points must not exceed 10000 combined'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMakerTakerBasisPointsError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = 'InvalidMakerTakerBasisPoints';
+  constructor() {
+    super('Maker and taker reward basis points must not exceed 10000 combined');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMakerTakerBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new InvalidMakerTakerBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidMakerTakerBasisPoints',
+  () => new InvalidMakerTakerBasisPointsError(),
+);
+
+/**
+ * InvalidRewardCurve: 'Reward curve tiers must be between 1 and 8, ordered by threshold, with nonzero denominators'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRewardCurveError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = 'InvalidRewardCurve';
+  constructor() {
+    super(
+      'Reward curve tiers must be between 1 and 8, ordered by threshold, with nonzero denominators',
+    );
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRewardCurveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new InvalidRewardCurveError());
+createErrorFromNameLookup.set('InvalidRewardCurve', () => new InvalidRewardCurveError());
+
+/**
+ * RewardCenterNotMigrated: 'Reward center must be migrated before using these reward rules'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCenterNotMigratedError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = 'RewardCenterNotMigrated';
+  constructor() {
+    super('Reward center must be migrated before using these reward rules');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCenterNotMigratedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new RewardCenterNotMigratedError());
+createErrorFromNameLookup.set('RewardCenterNotMigrated', () => new RewardCenterNotMigratedError());
+
+/**
+ * InvalidWashTradeWindow: 'Wash trade window must not be negative'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidWashTradeWindowError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = 'InvalidWashTradeWindow';
+  constructor() {
+    super('Wash trade window must not be negative');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidWashTradeWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new InvalidWashTradeWindowError());
+createErrorFromNameLookup.set('InvalidWashTradeWindow', () => new InvalidWashTradeWindowError());
+
+/**
+ * InvalidMinOrderAge: 'Minimum order age must not be negative'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMinOrderAgeError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'InvalidMinOrderAge';
+  constructor() {
+    super('Minimum order age must not be negative');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMinOrderAgeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InvalidMinOrderAgeError());
+createErrorFromNameLookup.set('InvalidMinOrderAge', () => new InvalidMinOrderAgeError());
+
+/**
+ * WalletRewardWindowMismatch: 'Wallet reward window does not match the wallet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletRewardWindowMismatchError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'WalletRewardWindowMismatch';
+  constructor() {
+    super('Wallet reward window does not match the wallet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletRewardWindowMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new WalletRewardWindowMismatchError());
+createErrorFromNameLookup.set(
+  'WalletRewardWindowMismatch',
+  () => new WalletRewardWindowMismatchError(),
+);
+
+/**
+ * SponsorPoolMismatch: 'Sponsor pool does not sponsor the collection of the NFT'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SponsorPoolMismatchError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'SponsorPoolMismatch';
+  constructor() {
+    super('Sponsor pool does not sponsor the collection of the NFT');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SponsorPoolMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new SponsorPoolMismatchError());
+createErrorFromNameLookup.set('SponsorPoolMismatch', () => new SponsorPoolMismatchError());
+
+/**
+ * RewardCenterHasOutstandingRewards: 'The reward center still owes rewards as reward debt or vesting'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCenterHasOutstandingRewardsError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'RewardCenterHasOutstandingRewards';
+  constructor() {
+    super('The reward center still owes rewards as reward debt or vesting');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCenterHasOutstandingRewardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new RewardCenterHasOutstandingRewardsError());
+createErrorFromNameLookup.set(
+  'RewardCenterHasOutstandingRewards',
+  () => new RewardCenterHasOutstandingRewardsError(),
+);
+
+/**
+ * RewardCenterHasUncountedObligations: 'The reward center may have uncounted orders or rewards and only closes when forced'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCenterHasUncountedObligationsError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'RewardCenterHasUncountedObligations';
+  constructor() {
+    super('The reward center may have uncounted orders or rewards and only closes when forced');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCenterHasUncountedObligationsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new RewardCenterHasUncountedObligationsError());
+createErrorFromNameLookup.set(
+  'RewardCenterHasUncountedObligations',
+  () => new RewardCenterHasUncountedObligationsError(),
+);
+
+/**
+ * CandidateCollectionOracle: 'Candidate reward rules cannot set a collection oracle'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CandidateCollectionOracleError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'CandidateCollectionOracle';
+  constructor() {
+    super('Candidate reward rules cannot set a collection oracle');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CandidateCollectionOracleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new CandidateCollectionOracleError());
+createErrorFromNameLookup.set(
+  'CandidateCollectionOracle',
+  () => new CandidateCollectionOracleError(),
+);
+
+/**
+ * OfferEscrowUnknown: 'The escrow of the offer is unknown, close it and place a new offer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferEscrowUnknownError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'OfferEscrowUnknown';
+  constructor() {
+    super('The escrow of the offer is unknown, close it and place a new offer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferEscrowUnknownError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new OfferEscrowUnknownError());
+createErrorFromNameLookup.set('OfferEscrowUnknown', () => new OfferEscrowUnknownError());
+
+/**
+ * OrderNotMigrated: 'The listing or offer has to be upgraded by migrate_reward_center first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OrderNotMigratedError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'OrderNotMigrated';
+  constructor() {
+    super('The listing or offer has to be upgraded by migrate_reward_center first');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OrderNotMigratedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new OrderNotMigratedError());
+createErrorFromNameLookup.set('OrderNotMigrated', () => new OrderNotMigratedError());
+
+/**
+ * RewardDebtAccountMissing: 'The reward treasury is short and the reward debt account of the wallet is missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardDebtAccountMissingError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'RewardDebtAccountMissing';
+  constructor() {
+    super('The reward treasury is short and the reward debt account of the wallet is missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardDebtAccountMissingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new RewardDebtAccountMissingError());
+createErrorFromNameLookup.set(
+  'RewardDebtAccountMissing',
+  () => new RewardDebtAccountMissingError(),
+);
+
+/**
+ * RewardVestingAccountMissing: 'Rewards are vested and the reward vesting account of the wallet is missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardVestingAccountMissingError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'RewardVestingAccountMissing';
+  constructor() {
+    super('Rewards are vested and the reward vesting account of the wallet is missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardVestingAccountMissingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new RewardVestingAccountMissingError());
+createErrorFromNameLookup.set(
+  'RewardVestingAccountMissing',
+  () => new RewardVestingAccountMissingError(),
+);
+
+/**
+ * SaleRecordAccountMissing: 'A wash trade window is set and the sale record account of the NFT is missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SaleRecordAccountMissingError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'SaleRecordAccountMissing';
+  constructor() {
+    super('A wash trade window is set and the sale record account of the NFT is missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SaleRecordAccountMissingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new SaleRecordAccountMissingError());
+createErrorFromNameLookup.set(
+  'SaleRecordAccountMissing',
+  () => new SaleRecordAccountMissingError(),
+);
+
+/**
+ * WalletRewardWindowMissing: 'Rewards are capped per wallet and the wallet reward window of the wallet is missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletRewardWindowMissingError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'WalletRewardWindowMissing';
+  constructor() {
+    super('Rewards are capped per wallet and the wallet reward window of the wallet is missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletRewardWindowMissingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new WalletRewardWindowMissingError());
+createErrorFromNameLookup.set(
+  'WalletRewardWindowMissing',
+  () => new WalletRewardWindowMissingError(),
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
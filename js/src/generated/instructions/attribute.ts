@@ -33,12 +33,27 @@ export const attributeStruct = new beet.FixableBeetArgsStruct<
   ],
   'AttributeInstructionArgs',
 );
+/**
+ * Accounts required by the _attribute_ instruction
+ *
+ * @property [**signer**] wallet
+ * @property [] attributor
+ * @category Instructions
+ * @category Attribute
+ * @category generated
+ */
+export type AttributeInstructionAccounts = {
+  wallet: web3.PublicKey;
+  attributor: web3.PublicKey;
+  anchorRemainingAccounts?: web3.AccountMeta[];
+};
 
 export const attributeInstructionDiscriminator = [242, 187, 207, 90, 187, 238, 242, 237];
 
 /**
  * Creates a _Attribute_ instruction.
  *
+ * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
  * @category Instructions
@@ -46,6 +61,7 @@ export const attributeInstructionDiscriminator = [242, 187, 207, 90, 187, 238, 2
  * @category generated
  */
 export function createAttributeInstruction(
+  accounts: AttributeInstructionAccounts,
   args: AttributeInstructionArgs,
   programId = new web3.PublicKey('rwdD3F6CgoCAoVaxcitXAeWRjQdiGc5AVABKCpQSMfd'),
 ) {
@@ -53,7 +69,24 @@ export function createAttributeInstruction(
     instructionDiscriminator: attributeInstructionDiscriminator,
     ...args,
   });
-  const keys: web3.AccountMeta[] = [];
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.wallet,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.attributor,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc);
+    }
+  }
 
   const ix = new web3.TransactionInstruction({
     programId,
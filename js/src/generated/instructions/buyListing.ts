@@ -58,8 +58,23 @@ export const buyListingStruct = new beet.BeetArgsStruct<
  * @property [_writable_] buyerTradeState
  * @property [_writable_] sellerTradeState
  * @property [_writable_] freeSellerTradeState
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [_writable_] rewardCenterRewardTokenAccount
+ * @property [] rewardableCollection
+ * @property [_writable_] buyerRewardDebt (optional)
+ * @property [_writable_] sellerRewardDebt (optional)
+ * @property [_writable_] buyerRewardVesting (optional)
+ * @property [_writable_] sellerRewardVesting (optional)
+ * @property [_writable_] buyerRewardWindow (optional)
+ * @property [_writable_] sellerRewardWindow (optional)
+ * @property [_writable_] purchaseTicket
+ * @property [_writable_] saleCounter
+ * @property [_writable_] saleRecord (optional)
+ * @property [] attributor (optional)
+ * @property [_writable_] attributorRewardTokenAccount (optional)
+ * @property [] sponsorPool (optional)
+ * @property [_writable_] sponsorPoolRewardTokenAccount (optional)
+ * @property [] collectionOracle (optional)
  * @property [] ahAuctioneerPda
  * @property [] programAsSigner
  * @property [] auctionHouseProgram
@@ -91,6 +106,21 @@ export type BuyListingInstructionAccounts = {
   freeSellerTradeState: web3.PublicKey;
   rewardCenter: web3.PublicKey;
   rewardCenterRewardTokenAccount: web3.PublicKey;
+  rewardableCollection: web3.PublicKey;
+  buyerRewardDebt?: web3.PublicKey;
+  sellerRewardDebt?: web3.PublicKey;
+  buyerRewardVesting?: web3.PublicKey;
+  sellerRewardVesting?: web3.PublicKey;
+  buyerRewardWindow?: web3.PublicKey;
+  sellerRewardWindow?: web3.PublicKey;
+  purchaseTicket: web3.PublicKey;
+  saleCounter: web3.PublicKey;
+  saleRecord?: web3.PublicKey;
+  attributor?: web3.PublicKey;
+  attributorRewardTokenAccount?: web3.PublicKey;
+  sponsorPool?: web3.PublicKey;
+  sponsorPoolRewardTokenAccount?: web3.PublicKey;
+  collectionOracle?: web3.PublicKey;
   ahAuctioneerPda: web3.PublicKey;
   programAsSigner: web3.PublicKey;
   auctionHouseProgram: web3.PublicKey;
@@ -230,7 +260,7 @@ export function createBuyListingInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -238,6 +268,81 @@ export function createBuyListingInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.rewardableCollection,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerRewardDebt ?? programId,
+      isWritable: accounts.buyerRewardDebt != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerRewardDebt ?? programId,
+      isWritable: accounts.sellerRewardDebt != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerRewardVesting ?? programId,
+      isWritable: accounts.buyerRewardVesting != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerRewardVesting ?? programId,
+      isWritable: accounts.sellerRewardVesting != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerRewardWindow ?? programId,
+      isWritable: accounts.buyerRewardWindow != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerRewardWindow ?? programId,
+      isWritable: accounts.sellerRewardWindow != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.purchaseTicket,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.saleCounter,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.saleRecord ?? programId,
+      isWritable: accounts.saleRecord != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.attributor ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.attributorRewardTokenAccount ?? programId,
+      isWritable: accounts.attributorRewardTokenAccount != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sponsorPool ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sponsorPoolRewardTokenAccount ?? programId,
+      isWritable: accounts.sponsorPoolRewardTokenAccount != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.collectionOracle ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.ahAuctioneerPda,
       isWritable: false,
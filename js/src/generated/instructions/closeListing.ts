@@ -29,7 +29,7 @@ export const closeListingStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] tokenAccount
  * @property [] tokenMint
  * @property [] authority
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [] auctionHouse
  * @property [_writable_] auctionHouseFeeAccount
  * @property [_writable_] tradeState
@@ -106,7 +106,7 @@ export function createCloseListingInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
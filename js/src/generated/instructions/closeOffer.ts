@@ -46,7 +46,7 @@ export const closeOfferStruct = new beet.BeetArgsStruct<
  * @property [_writable_] metadata
  * @property [] tokenMint
  * @property [] authority
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [] auctionHouse
  * @property [_writable_] auctionHouseFeeAccount
  * @property [_writable_] tradeState
@@ -148,7 +148,7 @@ export function createCloseOfferInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -23,7 +23,7 @@ export type CreateListingInstructionArgs = {
  * @category CreateListing
  * @category generated
  */
-export const createListingStruct = new beet.BeetArgsStruct<
+export const createListingStruct = new beet.FixableBeetArgsStruct<
   CreateListingInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -39,7 +39,7 @@ export const createListingStruct = new beet.BeetArgsStruct<
  *
  * @property [] auctionHouseProgram
  * @property [_writable_] listing
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [_writable_, **signer**] wallet
  * @property [_writable_] tokenAccount
  * @property [_writable_] metadata
@@ -108,7 +108,7 @@ export function createCreateListingInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -23,7 +23,7 @@ export type CreateOfferInstructionArgs = {
  * @category CreateOffer
  * @category generated
  */
-export const createOfferStruct = new beet.BeetArgsStruct<
+export const createOfferStruct = new beet.FixableBeetArgsStruct<
   CreateOfferInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -46,7 +46,7 @@ export const createOfferStruct = new beet.BeetArgsStruct<
  * @property [_writable_] metadata
  * @property [_writable_] escrowPaymentAccount
  * @property [] authority
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [] auctionHouse
  * @property [_writable_] auctionHouseFeeAccount
  * @property [_writable_] buyerTradeState
@@ -147,7 +147,7 @@ export function createCreateOfferInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -26,7 +26,7 @@ export type CreateRewardCenterInstructionArgs = {
  * @category CreateRewardCenter
  * @category generated
  */
-export const createRewardCenterStruct = new beet.BeetArgsStruct<
+export const createRewardCenterStruct = new beet.FixableBeetArgsStruct<
   CreateRewardCenterInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -25,7 +25,7 @@ export type EditRewardCenterInstructionArgs = {
  * @category EditRewardCenter
  * @category generated
  */
-export const editRewardCenterStruct = new beet.BeetArgsStruct<
+export const editRewardCenterStruct = new beet.FixableBeetArgsStruct<
   EditRewardCenterInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
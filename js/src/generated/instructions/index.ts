@@ -1,11 +1,30 @@
+export * from './acceptAuthority';
 export * from './acceptOffer';
+export * from './addAttributor';
+export * from './addRewardableCollection';
 export * from './attribute';
 export * from './buyListing';
+export * from './claimRewardDebt';
+export * from './claimVestedRewards';
+export * from './closeExpiredListing';
+export * from './closeExpiredOffer';
 export * from './closeListing';
 export * from './closeOffer';
+export * from './closePurchaseTicket';
+export * from './closeRewardCenter';
 export * from './createListing';
 export * from './createOffer';
 export * from './createRewardCenter';
+export * from './createSponsorPool';
+export * from './depositSponsorPoolFunds';
 export * from './editRewardCenter';
+export * from './migrateRewardCenter';
+export * from './proposeAuthority';
+export * from './removeAttributor';
+export * from './removeRewardableCollection';
+export * from './setPauseState';
+export * from './setRewardMode';
 export * from './updateListing';
+export * from './updateOffer';
 export * from './withdrawRewardCenterFunds';
+export * from './withdrawSponsorPoolFunds';
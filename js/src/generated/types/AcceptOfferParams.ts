@@ -12,6 +12,9 @@ export type AcceptOfferParams = {
   programAsSignerBump: number;
   sellerTradeStateBump: number;
   buyerTradeStateBump: number;
+  minPrice: beet.bignum;
+  listingBump: number;
+  purchaseTicketBump: number;
 };
 
 /**
@@ -25,6 +28,9 @@ export const acceptOfferParamsBeet = new beet.BeetArgsStruct<AcceptOfferParams>(
     ['programAsSignerBump', beet.u8],
     ['sellerTradeStateBump', beet.u8],
     ['buyerTradeStateBump', beet.u8],
+    ['minPrice', beet.u64],
+    ['listingBump', beet.u8],
+    ['purchaseTicketBump', beet.u8],
   ],
   'AcceptOfferParams',
 );
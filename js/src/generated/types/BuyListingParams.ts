@@ -12,6 +12,9 @@ export type BuyListingParams = {
   freeTradeStateBump: number;
   sellerTradeStateBump: number;
   programAsSignerBump: number;
+  maxPrice: beet.bignum;
+  offerBump: number;
+  purchaseTicketBump: number;
 };
 
 /**
@@ -25,6 +28,9 @@ export const buyListingParamsBeet = new beet.BeetArgsStruct<BuyListingParams>(
     ['freeTradeStateBump', beet.u8],
     ['sellerTradeStateBump', beet.u8],
     ['programAsSignerBump', beet.u8],
+    ['maxPrice', beet.u64],
+    ['offerBump', beet.u8],
+    ['purchaseTicketBump', beet.u8],
   ],
   'BuyListingParams',
 );
@@ -12,19 +12,21 @@ export type CreateListingParams = {
   tradeStateBump: number;
   freeTradeStateBump: number;
   programAsSignerBump: number;
+  expiresAt: beet.COption<beet.bignum>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const createListingParamsBeet = new beet.BeetArgsStruct<CreateListingParams>(
+export const createListingParamsBeet = new beet.FixableBeetArgsStruct<CreateListingParams>(
   [
     ['price', beet.u64],
     ['tokenSize', beet.u64],
     ['tradeStateBump', beet.u8],
     ['freeTradeStateBump', beet.u8],
     ['programAsSignerBump', beet.u8],
+    ['expiresAt', beet.coption(beet.i64)],
   ],
   'CreateListingParams',
 );
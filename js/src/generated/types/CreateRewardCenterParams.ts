@@ -15,7 +15,8 @@ export type CreateRewardCenterParams = {
  * @category userTypes
  * @category generated
  */
-export const createRewardCenterParamsBeet = new beet.BeetArgsStruct<CreateRewardCenterParams>(
-  [['rewardRules', rewardRulesBeet]],
-  'CreateRewardCenterParams',
-);
+export const createRewardCenterParamsBeet =
+  new beet.FixableBeetArgsStruct<CreateRewardCenterParams>(
+    [['rewardRules', rewardRulesBeet]],
+    'CreateRewardCenterParams',
+  );
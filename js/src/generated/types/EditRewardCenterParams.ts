@@ -15,7 +15,7 @@ export type EditRewardCenterParams = {
  * @category userTypes
  * @category generated
  */
-export const editRewardCenterParamsBeet = new beet.BeetArgsStruct<EditRewardCenterParams>(
+export const editRewardCenterParamsBeet = new beet.FixableBeetArgsStruct<EditRewardCenterParams>(
   [['rewardRules', rewardRulesBeet]],
   'EditRewardCenterParams',
 );
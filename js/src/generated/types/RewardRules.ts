@@ -5,23 +5,46 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { PayoutOperation, payoutOperationBeet } from './PayoutOperation';
+import { PayoutMode, payoutModeBeet } from './PayoutMode';
+import { MakerTakerSplit, makerTakerSplitBeet } from './MakerTakerSplit';
+import { RewardCurve, rewardCurveBeet } from './RewardCurve';
 export type RewardRules = {
   sellerRewardPayoutBasisPoints: number;
   mathematicalOperand: PayoutOperation;
   payoutNumeral: number;
+  payoutMode: PayoutMode;
+  attributorRewardBasisPoints: number;
+  collectionOracle: beet.COption<web3.PublicKey>;
+  makerTakerSplit: beet.COption<MakerTakerSplit>;
+  rewardCurve: RewardCurve;
+  washTradeWindowSeconds: beet.bignum;
+  minOrderAgeSeconds: beet.bignum;
+  maxRewardPerSale: beet.COption<beet.bignum>;
+  maxRewardPerWalletPerEpoch: beet.COption<beet.bignum>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const rewardRulesBeet = new beet.BeetArgsStruct<RewardRules>(
+export const rewardRulesBeet = new beet.FixableBeetArgsStruct<RewardRules>(
   [
     ['sellerRewardPayoutBasisPoints', beet.u16],
     ['mathematicalOperand', payoutOperationBeet],
     ['payoutNumeral', beet.u16],
+    ['payoutMode', payoutModeBeet],
+    ['attributorRewardBasisPoints', beet.u16],
+    ['collectionOracle', beet.coption(beetSolana.publicKey)],
+    ['makerTakerSplit', beet.coption(makerTakerSplitBeet)],
+    ['rewardCurve', rewardCurveBeet],
+    ['washTradeWindowSeconds', beet.i64],
+    ['minOrderAgeSeconds', beet.i64],
+    ['maxRewardPerSale', beet.coption(beet.u64)],
+    ['maxRewardPerWalletPerEpoch', beet.coption(beet.u64)],
   ],
   'RewardRules',
 );
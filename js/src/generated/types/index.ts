@@ -2,11 +2,28 @@ export * from './AcceptOfferParams';
 export * from './AttributeParams';
 export * from './BuyListingParams';
 export * from './CloseOfferParams';
+export * from './CloseRewardCenterParams';
 export * from './CreateListingParams';
 export * from './CreateOfferParams';
 export * from './CreateRewardCenterParams';
+export * from './CreateSponsorPoolParams';
+export * from './DepositSponsorPoolFundsParams';
 export * from './EditRewardCenterParams';
+export * from './MakerTakerSplit';
+export * from './PayoutMode';
 export * from './PayoutOperation';
+export * from './ProposeAuthorityParams';
+export * from './RewardCurve';
+export * from './RewardMode';
+export * from './RewardPayouts';
 export * from './RewardRules';
+export * from './RewardTier';
+export * from './RewardsWithheldReason';
+export * from './SalePath';
+export * from './SetPauseStateParams';
+export * from './SetRewardModeParams';
+export * from './SponsorRules';
 export * from './UpdateListingParams';
+export * from './UpdateOfferParams';
 export * from './WithdrawRewardCenterFundsParams';
+export * from './WithdrawSponsorPoolFundsParams';
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{ATTRIBUTOR, REWARD_CENTER},
+    errors::RewardCenterError,
+    state::{Attributor, RewardCenter},
+};
+
+/// Accounts for the [`add_attributor` handler](reward_center/fn.add_attributor.html).
+#[derive(Accounts, Clone)]
+pub struct AddAttributor<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: Any wallet can receive attribution rewards.
+    /// The wallet of the front-end or referrer receiving the attribution rewards.
+    pub attributor_wallet: UncheckedAccount<'info>,
+
+    /// The record registering the wallet as an attributor of the reward center.
+    #[account(
+        init,
+        payer = wallet,
+        space = Attributor::size(),
+        seeds = [
+            ATTRIBUTOR.as_bytes(),
+            reward_center.key().as_ref(),
+            attributor_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub attributor: Account<'info, Attributor>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddAttributor>) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let attributor_wallet = &ctx.accounts.attributor_wallet;
+    let attributor = &mut ctx.accounts.attributor;
+
+    attributor.reward_center = reward_center.key();
+    attributor.wallet = attributor_wallet.key();
+    attributor.bump = *ctx
+        .bumps
+        .get(ATTRIBUTOR)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -1,17 +1,50 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{ATTRIBUTOR, MAX_ATTRIBUTION_MEMO_LEN},
+    errors::RewardCenterError,
+    events::AttributionMemo,
+    state::Attributor,
+};
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AttributeParams {
     pub memo: String,
 }
 
+/// Accounts for the [`attribute` handler](reward_center/fn.attribute.html).
 #[derive(Accounts, Clone)]
 #[instruction(attribute_params: AttributeParams)]
-pub struct Attribute {}
+pub struct Attribute<'info> {
+    /// The payout wallet of the attributor, vouching for the memo.
+    pub wallet: Signer<'info>,
+
+    /// The attributor the memo is recorded for.
+    #[account(
+        seeds = [
+            ATTRIBUTOR.as_bytes(),
+            attributor.reward_center.as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump = attributor.bump
+    )]
+    pub attributor: Account<'info, Attributor>,
+}
+
+pub fn handler(ctx: Context<Attribute>, AttributeParams { memo }: AttributeParams) -> Result<()> {
+    require_gte!(
+        MAX_ATTRIBUTION_MEMO_LEN,
+        memo.len(),
+        RewardCenterError::AttributionMemoTooLong
+    );
+
+    let attributor = &ctx.accounts.attributor;
+
+    emit!(AttributionMemo {
+        reward_center: attributor.reward_center,
+        attributor: attributor.key(),
+        memo,
+    });
 
-pub fn handler(
-    _ctx: Context<Attribute>,
-    AttributeParams { memo: _ }: AttributeParams,
-) -> Result<()> {
     Ok(())
 }
@@ -1 +1,3 @@
+pub mod add;
 pub mod attribute;
+pub mod remove;
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{ATTRIBUTOR, REWARD_CENTER},
+    errors::RewardCenterError,
+    state::{Attributor, RewardCenter},
+};
+
+/// Accounts for the [`remove_attributor` handler](reward_center/fn.remove_attributor.html).
+#[derive(Accounts, Clone)]
+pub struct RemoveAttributor<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The record registering the wallet as an attributor of the reward center.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            ATTRIBUTOR.as_bytes(),
+            reward_center.key().as_ref(),
+            attributor.wallet.as_ref()
+        ],
+        bump = attributor.bump,
+        close = wallet
+    )]
+    pub attributor: Account<'info, Attributor>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+}
+
+pub fn handler(_ctx: Context<RemoveAttributor>) -> Result<()> {
+    Ok(())
+}
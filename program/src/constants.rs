@@ -5,3 +5,21 @@ pub const LISTING: &str = "listing";
 pub const OFFER: &str = "offer";
 
 pub const PURCHASE_TICKET: &str = "purchase_ticket";
+
+pub const SALE_RECORD: &str = "sale_record";
+
+pub const SALE_COUNTER: &str = "sale_counter";
+
+pub const REWARDABLE_COLLECTION: &str = "rewardable_collection";
+
+pub const REWARD_DEBT: &str = "reward_debt";
+
+pub const REWARD_VESTING: &str = "reward_vesting";
+
+pub const WALLET_REWARD_WINDOW: &str = "wallet_reward_window";
+
+pub const ATTRIBUTOR: &str = "attributor";
+
+pub const SPONSOR_POOL: &str = "sponsor_pool";
+
+pub const MAX_ATTRIBUTION_MEMO_LEN: usize = 256;
@@ -55,4 +55,170 @@ pub enum RewardCenterError {
     // 6012
     #[msg("The listing price cannot be zero")]
     PriceInvalid,
+
+    // 6013
+    #[msg("The listing price exceeds the maximum price set by the buyer")]
+    PriceExceedsMaximum,
+
+    // 6014
+    #[msg("The offer price is below the minimum price set by the seller")]
+    PriceBelowMinimum,
+
+    // 6015
+    #[msg("The expiry must be in the future")]
+    ExpiryInvalid,
+
+    // 6016
+    #[msg("The listing has expired")]
+    ListingExpired,
+
+    // 6017
+    #[msg("The listing has not expired yet")]
+    ListingNotExpired,
+
+    // 6018
+    #[msg("The offer has expired")]
+    OfferExpired,
+
+    // 6019
+    #[msg("The offer has not expired yet")]
+    OfferNotExpired,
+
+    // 6020
+    #[msg("The reward debt account does not belong to the wallet")]
+    RewardDebtMismatch,
+
+    // 6021
+    #[msg("The reward vesting account does not belong to the wallet")]
+    RewardVestingMismatch,
+
+    // 6022
+    #[msg("There are no vested rewards to claim")]
+    NoVestedRewards,
+
+    // 6023
+    #[msg("Seller reward payout basis points cannot exceed 10000")]
+    InvalidSellerRewardPayoutBasisPoints,
+
+    // 6024
+    #[msg("Payout numeral must be greater than zero when dividing")]
+    InvalidPayoutNumeral,
+
+    // 6025
+    #[msg(
+        "Vesting cliff and duration must be non-negative and the cliff cannot exceed the duration"
+    )]
+    InvalidVestingSchedule,
+
+    // 6026
+    #[msg("The signer is not the proposed reward center authority")]
+    PendingAuthorityMismatch,
+
+    // 6027
+    #[msg("Listings are paused for this reward center")]
+    ListingsPaused,
+
+    // 6028
+    #[msg("Offers are paused for this reward center")]
+    OffersPaused,
+
+    // 6029
+    #[msg("Sales are paused for this reward center")]
+    SalesPaused,
+
+    // 6030
+    #[msg("Rewards are paused for this reward center")]
+    RewardsPaused,
+
+    // 6031
+    #[msg("The pause state contains unknown flags")]
+    InvalidPauseState,
+
+    // 6032
+    #[msg("The reward center still has open listings or offers")]
+    RewardCenterHasOpenOrders,
+
+    // 6033
+    #[msg("Account is not a listing or offer of the reward center")]
+    InvalidMigrationAccount,
+
+    // 6034
+    #[msg("Purchase ticket does not match the sale")]
+    PurchaseTicketMismatch,
+
+    // 6035
+    #[msg("Attributor reward basis points must not exceed 10000")]
+    InvalidAttributorRewardBasisPoints,
+
+    // 6036
+    #[msg("Attributor and its reward token account must be passed together")]
+    AttributorMismatch,
+
+    // 6037
+    #[msg("Attribution memo is too long")]
+    AttributionMemoTooLong,
+
+    // 6038
+    #[msg("Maker and taker reward basis points must not exceed 10000 combined")]
+    InvalidMakerTakerBasisPoints,
+
+    // 6039
+    #[msg("Reward curve tiers must be between 1 and 8, ordered by threshold, with nonzero denominators")]
+    InvalidRewardCurve,
+
+    // 6040
+    #[msg("Reward center must be migrated before using these reward rules")]
+    RewardCenterNotMigrated,
+
+    // 6041
+    #[msg("Wash trade window must not be negative")]
+    InvalidWashTradeWindow,
+
+    // 6042
+    #[msg("Minimum order age must not be negative")]
+    InvalidMinOrderAge,
+
+    // 6043
+    #[msg("Wallet reward window does not match the wallet")]
+    WalletRewardWindowMismatch,
+
+    // 6044
+    #[msg("Sponsor pool does not sponsor the collection of the NFT")]
+    SponsorPoolMismatch,
+
+    // 6045
+    #[msg("The reward center still owes rewards as reward debt or vesting")]
+    RewardCenterHasOutstandingRewards,
+
+    // 6046
+    #[msg("The reward center may have uncounted orders or rewards and only closes when forced")]
+    RewardCenterHasUncountedObligations,
+
+    // 6047
+    #[msg("Candidate reward rules cannot set a collection oracle")]
+    CandidateCollectionOracle,
+
+    // 6048
+    #[msg("The escrow of the offer is unknown, close it and place a new offer")]
+    OfferEscrowUnknown,
+
+    // 6049
+    #[msg("The listing or offer has to be upgraded by migrate_reward_center first")]
+    OrderNotMigrated,
+
+    // 6050
+    #[msg("The reward treasury is short and the reward debt account of the wallet is missing")]
+    RewardDebtAccountMissing,
+
+    // 6051
+    #[msg("Rewards are vested and the reward vesting account of the wallet is missing")]
+    RewardVestingAccountMissing,
+
+    // 6052
+    #[msg("A wash trade window is set and the sale record account of the NFT is missing")]
+    SaleRecordAccountMissing,
+
+    // 6053
+    #[msg("Rewards are capped per wallet and the wallet reward window of the wallet is missing")]
+    WalletRewardWindowMissing,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::RewardMode;
+
 #[event]
 pub struct RewardCenterTreasuryWithdrawn {
     pub rewards_mint: Pubkey,
@@ -7,3 +9,184 @@ pub struct RewardCenterTreasuryWithdrawn {
     pub destination_reward_token_account: Pubkey,
     pub withdrawal_amount: u64,
 }
+
+#[event]
+pub struct SponsorPoolFunded {
+    pub sponsor_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SponsorPoolWithdrawn {
+    pub sponsor_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub destination_reward_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardShortfall {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub shortfall_amount: u64,
+    pub total_debt: u64,
+}
+
+#[event]
+pub struct ListingCreated {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct ListingUpdated {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub previous_price: u64,
+    pub price: u64,
+}
+
+#[event]
+pub struct ListingClosed {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    /// whether the listing was closed after it expired rather than by the seller
+    pub expired: bool,
+}
+
+#[event]
+pub struct OfferCreated {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub price_with_fees: u64,
+    pub token_size: u64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct OfferUpdated {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub previous_price: u64,
+    pub price: u64,
+    pub price_with_fees: u64,
+}
+
+#[event]
+pub struct OfferClosed {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    /// whether the offer was closed after it expired rather than by the buyer
+    pub expired: bool,
+}
+
+#[event]
+pub struct SaleExecuted {
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub auction_house_fee: u64,
+    pub royalty_fee: u64,
+    pub seller_payout: u64,
+    pub buyer_payout: u64,
+    /// whether the seller payout was recorded as reward debt for lack of treasury funds
+    pub seller_payout_skipped: bool,
+    /// whether the buyer payout was recorded as reward debt for lack of treasury funds
+    pub buyer_payout_skipped: bool,
+}
+
+/// Why a sale earned no rewards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum RewardsWithheldReason {
+    RewardsPaused,
+    CollectionNotRewardable,
+    CollectionOracleNotApproved,
+    OrderTooYoung,
+    WashTrade,
+}
+
+#[event]
+pub struct RewardsWithheld {
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub reason: RewardsWithheldReason,
+}
+
+/// Rewards of a sale for the seller, the buyer and the attributor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RewardPayouts {
+    pub seller_payout: u64,
+    pub buyer_payout: u64,
+    pub attributor_payout: u64,
+}
+
+#[event]
+pub struct RewardsEvaluated {
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    /// in shadow mode the payouts were only reported, not paid
+    pub reward_mode: RewardMode,
+    /// payouts under the reward rules, capped per sale but not yet per wallet
+    pub payouts: RewardPayouts,
+    /// payouts under the candidate reward rules, if any, capped per sale but never per wallet
+    pub candidate_payouts: Option<RewardPayouts>,
+}
+
+#[event]
+pub struct SponsorRewardsPaid {
+    pub reward_center: Pubkey,
+    pub sponsor_pool: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payout: u64,
+    pub buyer_payout: u64,
+    /// whether the payouts were skipped for lack of pool funds or in shadow mode
+    pub skipped: bool,
+}
+
+#[event]
+pub struct SaleAttributed {
+    pub reward_center: Pubkey,
+    pub attributor: Pubkey,
+    pub attributor_wallet: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub attributor_payout: u64,
+    /// whether the attributor payout was skipped for lack of treasury funds
+    pub attributor_payout_skipped: bool,
+}
+
+#[event]
+pub struct AttributionMemo {
+    pub reward_center: Pubkey,
+    pub attributor: Pubkey,
+    pub memo: String,
+}
@@ -6,17 +6,34 @@ pub mod listings;
 pub mod metaplex_cpi;
 pub mod offers;
 pub mod pda;
+pub mod purchase_tickets;
 pub mod reward_centers;
+pub mod reward_debts;
+pub mod reward_vestings;
+pub mod rewardable_collections;
+pub mod rewards;
+pub mod sale_records;
+pub mod sponsor_pools;
 pub mod state;
+pub mod utils;
+pub mod wallet_reward_windows;
 pub mod withdraw;
 
 use anchor_lang::prelude::*;
 
 use crate::{
-    attribution::attribute::*,
-    listings::{buy::*, close::*, create::*, update::*},
-    offers::{accept::*, close::*, create::*},
-    reward_centers::{create::*, edit::*},
+    attribution::{add::*, attribute::*, remove::*},
+    listings::{buy::*, close::*, close_expired::*, create::*, update::*},
+    offers::{accept::*, close::*, close_expired::*, create::*, update::*},
+    purchase_tickets::close::*,
+    reward_centers::{
+        accept_authority::*, close::*, create::*, edit::*, migrate::*, propose_authority::*,
+        set_pause_state::*, set_reward_mode::*,
+    },
+    reward_debts::claim::*,
+    reward_vestings::claim::*,
+    rewardable_collections::{add::*, remove::*},
+    sponsor_pools::{create::*, deposit::*, withdraw::*},
     withdraw::reward_center::*,
 };
 
@@ -40,6 +57,37 @@ pub mod reward_center {
         reward_centers::edit::handler(ctx, edit_reward_center_params)
     }
 
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        propose_authority_params: ProposeAuthorityParams,
+    ) -> Result<()> {
+        reward_centers::propose_authority::handler(ctx, propose_authority_params)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        reward_centers::accept_authority::handler(ctx)
+    }
+
+    pub fn set_pause_state(
+        ctx: Context<SetPauseState>,
+        set_pause_state_params: SetPauseStateParams,
+    ) -> Result<()> {
+        reward_centers::set_pause_state::handler(ctx, set_pause_state_params)
+    }
+
+    pub fn set_reward_mode(
+        ctx: Context<SetRewardMode>,
+        set_reward_mode_params: SetRewardModeParams,
+    ) -> Result<()> {
+        reward_centers::set_reward_mode::handler(ctx, set_reward_mode_params)
+    }
+
+    pub fn migrate_reward_center<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateRewardCenter<'info>>,
+    ) -> Result<()> {
+        reward_centers::migrate::handler(ctx)
+    }
+
     pub fn withdraw_reward_center_funds(
         ctx: Context<WithdrawRewardCenterFunds>,
         withdraw_reward_center_funds_params: WithdrawRewardCenterFundsParams,
@@ -47,6 +95,29 @@ pub mod reward_center {
         withdraw::reward_center::handler(ctx, withdraw_reward_center_funds_params)
     }
 
+    pub fn close_reward_center(
+        ctx: Context<CloseRewardCenter>,
+        close_reward_center_params: CloseRewardCenterParams,
+    ) -> Result<()> {
+        reward_centers::close::handler(ctx, close_reward_center_params)
+    }
+
+    pub fn claim_reward_debt(ctx: Context<ClaimRewardDebt>) -> Result<()> {
+        reward_debts::claim::handler(ctx)
+    }
+
+    pub fn claim_vested_rewards(ctx: Context<ClaimVestedRewards>) -> Result<()> {
+        reward_vestings::claim::handler(ctx)
+    }
+
+    pub fn add_rewardable_collection(ctx: Context<AddRewardableCollection>) -> Result<()> {
+        rewardable_collections::add::handler(ctx)
+    }
+
+    pub fn remove_rewardable_collection(ctx: Context<RemoveRewardableCollection>) -> Result<()> {
+        rewardable_collections::remove::handler(ctx)
+    }
+
     pub fn create_listing<'info>(
         ctx: Context<'_, '_, '_, 'info, CreateListing<'info>>,
         create_listing_params: CreateListingParams,
@@ -67,6 +138,12 @@ pub mod reward_center {
         listings::close::handler(ctx)
     }
 
+    pub fn close_expired_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>,
+    ) -> Result<()> {
+        listings::close_expired::handler(ctx)
+    }
+
     pub fn create_offer(
         ctx: Context<CreateOffer>,
         create_offer_params: CreateOfferParams,
@@ -74,6 +151,13 @@ pub mod reward_center {
         offers::create::handler(ctx, create_offer_params)
     }
 
+    pub fn update_offer(
+        ctx: Context<UpdateOffer>,
+        update_offer_params: UpdateOfferParams,
+    ) -> Result<()> {
+        offers::update::handler(ctx, update_offer_params)
+    }
+
     pub fn close_offer(
         ctx: Context<CloseOffer>,
         close_offer_params: CloseOfferParams,
@@ -81,6 +165,13 @@ pub mod reward_center {
         offers::close::handler(ctx, close_offer_params)
     }
 
+    pub fn close_expired_offer(
+        ctx: Context<CloseExpiredOffer>,
+        close_offer_params: CloseOfferParams,
+    ) -> Result<()> {
+        offers::close_expired::handler(ctx, close_offer_params)
+    }
+
     pub fn buy_listing<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>,
         buy_listing_params: BuyListingParams,
@@ -95,10 +186,40 @@ pub mod reward_center {
         offers::accept::handler(ctx, accept_offer_params)
     }
 
-    pub fn attribute<'info>(
-        ctx: Context<Attribute>,
-        attribute_params: AttributeParams,
-    ) -> Result<()> {
+    pub fn close_purchase_ticket(ctx: Context<ClosePurchaseTicket>) -> Result<()> {
+        purchase_tickets::close::handler(ctx)
+    }
+
+    pub fn add_attributor(ctx: Context<AddAttributor>) -> Result<()> {
+        attribution::add::handler(ctx)
+    }
+
+    pub fn remove_attributor(ctx: Context<RemoveAttributor>) -> Result<()> {
+        attribution::remove::handler(ctx)
+    }
+
+    pub fn attribute(ctx: Context<Attribute>, attribute_params: AttributeParams) -> Result<()> {
         attribution::attribute::handler(ctx, attribute_params)
     }
+
+    pub fn create_sponsor_pool(
+        ctx: Context<CreateSponsorPool>,
+        create_sponsor_pool_params: CreateSponsorPoolParams,
+    ) -> Result<()> {
+        sponsor_pools::create::handler(ctx, create_sponsor_pool_params)
+    }
+
+    pub fn deposit_sponsor_pool_funds(
+        ctx: Context<DepositSponsorPoolFunds>,
+        deposit_sponsor_pool_funds_params: DepositSponsorPoolFundsParams,
+    ) -> Result<()> {
+        sponsor_pools::deposit::handler(ctx, deposit_sponsor_pool_funds_params)
+    }
+
+    pub fn withdraw_sponsor_pool_funds(
+        ctx: Context<WithdrawSponsorPoolFunds>,
+        withdraw_sponsor_pool_funds_params: WithdrawSponsorPoolFundsParams,
+    ) -> Result<()> {
+        sponsor_pools::withdraw::handler(ctx, withdraw_sponsor_pool_funds_params)
+    }
 }
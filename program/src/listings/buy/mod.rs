@@ -1,8 +1,14 @@
 use crate::{
-    constants::{LISTING, REWARD_CENTER},
+    constants::{
+        LISTING, OFFER, REWARD_CENTER, REWARD_DEBT, REWARD_VESTING, SALE_COUNTER, SALE_RECORD,
+        WALLET_REWARD_WINDOW,
+    },
     errors::RewardCenterError,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
-    state::{Listing, RewardCenter},
+    rewards::{settle_sale, SaleSettlement, SaleTerms, SaleWallet},
+    state::{
+        Attributor, Listing, PauseFlags, RewardCenter, RewardableCollection, SalePath, SponsorPool,
+    },
 };
 use anchor_lang::{
     prelude::{Result, *},
@@ -10,7 +16,7 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer, Mint, Token, TokenAccount, Transfer},
+    token::{Mint, Token, TokenAccount},
 };
 use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
 use mtly_auction_house::{
@@ -30,6 +36,11 @@ pub struct BuyListingParams {
     pub free_trade_state_bump: u8,
     pub seller_trade_state_bump: u8,
     pub program_as_signer_bump: u8,
+    pub max_price: u64,
+    /// bump of the offer address of the buyer for the NFT, keying the purchase ticket
+    pub offer_bump: u8,
+    /// bump of the purchase ticket address of the sale
+    pub purchase_ticket_bump: u8,
 }
 
 #[derive(Accounts, Clone)]
@@ -236,6 +247,7 @@ pub struct BuyListing<'info> {
     /// CHECK: Verified through CPI
     /// The auctioneer authority PDA running this auction.
     #[account(
+        mut,
         has_one = auction_house,
         seeds = [
             REWARD_CENTER.as_bytes(),
@@ -254,6 +266,137 @@ pub struct BuyListing<'info> {
     /// The token account holding the reward token for the reward center.
     pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// CHECK: Checked against the verified collection of the metadata in the handler.
+    /// The rewardable collection record of the collection the NFT belongs to.
+    pub rewardable_collection: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when the treasury cannot cover the buyer reward.
+    /// The reward debt record of the buyer, only needed in immediate payout mode when the treasury is short.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_DEBT.as_bytes(),
+            reward_center.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub buyer_reward_debt: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when the treasury cannot cover the seller reward.
+    /// The reward debt record of the seller, only needed in immediate payout mode when the treasury is short.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_DEBT.as_bytes(),
+            reward_center.key().as_ref(),
+            seller.key().as_ref()
+        ],
+        bump
+    )]
+    pub seller_reward_debt: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when buyer rewards are vested.
+    /// The reward vesting record of the buyer, only needed in vested payout mode.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_VESTING.as_bytes(),
+            reward_center.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub buyer_reward_vesting: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when seller rewards are vested.
+    /// The reward vesting record of the seller, only needed in vested payout mode.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_VESTING.as_bytes(),
+            reward_center.key().as_ref(),
+            seller.key().as_ref()
+        ],
+        bump
+    )]
+    pub seller_reward_vesting: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when buyer rewards are capped.
+    /// The wallet reward window of the buyer, only needed while rewards are capped per wallet.
+    #[account(
+        mut,
+        seeds = [
+            WALLET_REWARD_WINDOW.as_bytes(),
+            reward_center.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub buyer_reward_window: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler when seller rewards are capped.
+    /// The wallet reward window of the seller, only needed while rewards are capped per wallet.
+    #[account(
+        mut,
+        seeds = [
+            WALLET_REWARD_WINDOW.as_bytes(),
+            reward_center.key().as_ref(),
+            seller.key().as_ref()
+        ],
+        bump
+    )]
+    pub seller_reward_window: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Seeds checked in the handler. Created in the handler.
+    /// The purchase ticket recording the sale, numbered by the sale counter of the NFT.
+    #[account(mut)]
+    pub purchase_ticket: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler on the first sale of the NFT.
+    /// The sale counter of the NFT, numbering its purchase tickets.
+    #[account(
+        mut,
+        seeds = [
+            SALE_COUNTER.as_bytes(),
+            reward_center.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub sale_counter: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds checked in constraint. Created in the handler by the first sale of the NFT with rewards at stake.
+    /// The sale record of the NFT, used to withhold rewards from wash trades, only needed while the wash trade window is set.
+    #[account(
+        mut,
+        seeds = [
+            SALE_RECORD.as_bytes(),
+            reward_center.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub sale_record: Option<UncheckedAccount<'info>>,
+
+    /// The attributor credited with the sale, if any.
+    pub attributor: Option<Box<Account<'info, Attributor>>>,
+
+    /// The reward token account of the attributor wallet, passed along with the attributor.
+    #[account(mut)]
+    pub attributor_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The sponsor pool of the collection the NFT belongs to, if any.
+    pub sponsor_pool: Option<Box<Account<'info, SponsorPool>>>,
+
+    /// The token account holding the reward tokens of the sponsor pool, passed along with the sponsor pool.
+    #[account(mut)]
+    pub sponsor_pool_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Compared against the collection oracle of the reward center in the handler.
+    /// The collection oracle approving the rewards of the sale by signing the transaction.
+    pub collection_oracle: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Not dangerous. Account seeds checked in constraint.
     /// The auctioneer PDA owned by Auction House storing scopes.
     #[account(
@@ -297,6 +440,9 @@ pub fn handler<'info>(
         escrow_payment_bump,
         program_as_signer_bump,
         free_trade_state_bump,
+        max_price,
+        offer_bump,
+        purchase_ticket_bump,
         ..
     }: BuyListingParams,
 ) -> Result<()> {
@@ -306,14 +452,42 @@ pub fn handler<'info>(
     let token_account = &ctx.accounts.token_account;
     let listing = &ctx.accounts.listing;
 
+    require!(
+        !reward_center.is_paused(PauseFlags::SALES),
+        RewardCenterError::SalesPaused
+    );
+
+    // The order age is read from updated_at, which earlier versions did not record
+    require_eq!(
+        listing.version,
+        Listing::VERSION,
+        RewardCenterError::OrderNotMigrated
+    );
+
+    require!(
+        !listing.is_expired(Clock::get()?.unix_timestamp),
+        RewardCenterError::ListingExpired
+    );
+
+    require!(
+        listing.price <= max_price,
+        RewardCenterError::PriceExceedsMaximum
+    );
+
     let listing_price = listing.price;
     let token_size = listing.token_size;
     let auction_house_key = auction_house.key();
 
     let md = Box::new(Metadata::from_account_info(&metadata.to_account_info())?);
-    let price = listing_price
-        + ((auction_house.seller_fee_basis_points as u64 * listing_price) / 10000)
-        + ((md.data.seller_fee_basis_points as u64 * listing_price) / 10000);
+    let auction_house_fee = (auction_house.seller_fee_basis_points as u64 * listing_price) / 10000;
+    let royalty_fee = (md.data.seller_fee_basis_points as u64 * listing_price) / 10000;
+    let price = listing_price + auction_house_fee + royalty_fee;
+
+    let is_rewardable = RewardableCollection::is_registered(
+        &ctx.accounts.rewardable_collection,
+        &reward_center.key(),
+        &md,
+    );
 
     let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
         REWARD_CENTER.as_bytes(),
@@ -443,52 +617,118 @@ pub fn handler<'info>(
         )?;
     }
 
-    {
-        let (seller_payout, buyer_payout) = reward_center.payouts(listing_price)?;
-
-        // Buyer transfer
-        let reward_center_reward_token_balance =
-            ctx.accounts.reward_center_reward_token_account.amount;
-        if buyer_payout > 0 && reward_center_reward_token_balance >= buyer_payout {
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        authority: ctx.accounts.reward_center.to_account_info(),
-                        from: ctx
-                            .accounts
-                            .reward_center_reward_token_account
-                            .to_account_info(),
-                        to: ctx.accounts.buyer_reward_token_account.to_account_info(),
-                    },
-                    reward_center_signer_seeds,
-                ),
-                buyer_payout,
-            )?;
-        }
-
-        // Seller transfer
-        ctx.accounts.reward_center_reward_token_account.reload()?;
-        let reward_center_reward_token_balance =
-            ctx.accounts.reward_center_reward_token_account.amount;
-        if seller_payout > 0 && reward_center_reward_token_balance >= seller_payout {
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        authority: ctx.accounts.reward_center.to_account_info(),
-                        from: ctx
-                            .accounts
-                            .reward_center_reward_token_account
-                            .to_account_info(),
-                        to: ctx.accounts.seller_reward_token_account.to_account_info(),
-                    },
-                    reward_center_signer_seeds,
-                ),
-                seller_payout,
-            )?
-        };
-    }
+    let is_order_too_young = listing.is_younger_than(
+        Clock::get()?.unix_timestamp,
+        reward_center.reward_rules.min_order_age_seconds,
+    );
 
-    Ok(())
+    let offer = Pubkey::create_program_address(
+        &[
+            OFFER.as_bytes(),
+            ctx.accounts.buyer.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref(),
+            &[offer_bump],
+        ],
+        &crate::id(),
+    )
+    .map_err(|_| RewardCenterError::PurchaseTicketMismatch)?;
+
+    settle_sale(
+        SaleSettlement {
+            reward_center: &mut ctx.accounts.reward_center,
+            reward_center_reward_token_account: &mut ctx
+                .accounts
+                .reward_center_reward_token_account,
+            buyer: SaleWallet {
+                wallet: ctx.accounts.buyer.key(),
+                reward_token_account: ctx.accounts.buyer_reward_token_account.to_account_info(),
+                reward_debt: ctx
+                    .accounts
+                    .buyer_reward_debt
+                    .as_ref()
+                    .map(|reward_debt| reward_debt.to_account_info()),
+                reward_debt_bump: ctx.bumps.get("buyer_reward_debt").copied(),
+                reward_vesting: ctx
+                    .accounts
+                    .buyer_reward_vesting
+                    .as_ref()
+                    .map(|reward_vesting| reward_vesting.to_account_info()),
+                reward_vesting_bump: ctx.bumps.get("buyer_reward_vesting").copied(),
+                reward_window: ctx
+                    .accounts
+                    .buyer_reward_window
+                    .as_ref()
+                    .map(|reward_window| reward_window.to_account_info()),
+                reward_window_bump: ctx.bumps.get("buyer_reward_window").copied(),
+            },
+            seller: SaleWallet {
+                wallet: ctx.accounts.seller.key(),
+                reward_token_account: ctx.accounts.seller_reward_token_account.to_account_info(),
+                reward_debt: ctx
+                    .accounts
+                    .seller_reward_debt
+                    .as_ref()
+                    .map(|reward_debt| reward_debt.to_account_info()),
+                reward_debt_bump: ctx.bumps.get("seller_reward_debt").copied(),
+                reward_vesting: ctx
+                    .accounts
+                    .seller_reward_vesting
+                    .as_ref()
+                    .map(|reward_vesting| reward_vesting.to_account_info()),
+                reward_vesting_bump: ctx.bumps.get("seller_reward_vesting").copied(),
+                reward_window: ctx
+                    .accounts
+                    .seller_reward_window
+                    .as_ref()
+                    .map(|reward_window| reward_window.to_account_info()),
+                reward_window_bump: ctx.bumps.get("seller_reward_window").copied(),
+            },
+            mint: ctx.accounts.token_mint.key(),
+            metadata: ctx.accounts.metadata.key(),
+            listing: ctx.accounts.listing.key(),
+            offer,
+            sale_record: ctx
+                .accounts
+                .sale_record
+                .as_ref()
+                .map(|sale_record| sale_record.to_account_info()),
+            sale_record_bump: ctx.bumps.get("sale_record").copied(),
+            purchase_ticket: ctx.accounts.purchase_ticket.to_account_info(),
+            purchase_ticket_bump,
+            sale_counter: ctx.accounts.sale_counter.to_account_info(),
+            sale_counter_bump: *ctx
+                .bumps
+                .get("sale_counter")
+                .ok_or(RewardCenterError::BumpSeedNotInHashMap)?,
+            attributor: ctx.accounts.attributor.as_deref(),
+            attributor_reward_token_account: ctx
+                .accounts
+                .attributor_reward_token_account
+                .as_deref(),
+            sponsor_pool: ctx.accounts.sponsor_pool.as_deref(),
+            sponsor_pool_reward_token_account: ctx
+                .accounts
+                .sponsor_pool_reward_token_account
+                .as_deref_mut(),
+            collection_oracle: ctx
+                .accounts
+                .collection_oracle
+                .as_ref()
+                .map(|collection_oracle| collection_oracle.as_ref()),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        SaleTerms {
+            price: listing_price,
+            token_size,
+            auction_house_fee,
+            royalty_fee,
+            metadata: &md,
+            is_rewardable,
+            is_order_too_young,
+        },
+        SalePath::BuyListing,
+        ctx.accounts.buyer.to_account_info(),
+    )
 }
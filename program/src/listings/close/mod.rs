@@ -1,5 +1,6 @@
 use crate::{
     constants::{LISTING, REWARD_CENTER},
+    events::ListingClosed,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
     state::{Listing, RewardCenter},
 };
@@ -50,8 +51,9 @@ pub struct CloseListing<'info> {
     /// Auction House instance authority account.
     pub authority: UncheckedAccount<'info>,
 
-    /// The auctioneer program PDA running this auction.
+    /// The auctioneer program PDA running this auction, writable to stop counting the listing.
     #[account(
+        mut,
         seeds = [
             REWARD_CENTER.as_bytes(),
             auction_house.key().as_ref()
@@ -159,5 +161,16 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseListing<'info>>) -> R
         reward_center_signer_seeds,
     )?;
 
+    emit!(ListingClosed {
+        reward_center: listing.reward_center,
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        expired: false,
+    });
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.open_listings = reward_center.open_listings.saturating_sub(1);
+
     Ok(())
 }
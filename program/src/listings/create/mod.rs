@@ -5,8 +5,9 @@ use solana_program::program::invoke_signed;
 use crate::{
     constants::{LISTING, REWARD_CENTER},
     errors::RewardCenterError,
+    events::ListingCreated,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
-    state::{Listing, RewardCenter},
+    state::{Listing, PauseFlags, RewardCenter},
 };
 use mtly_auction_house::{
     constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
@@ -24,6 +25,7 @@ pub struct CreateListingParams {
     pub trade_state_bump: u8,
     pub free_trade_state_bump: u8,
     pub program_as_signer_bump: u8,
+    pub expires_at: Option<i64>,
 }
 
 /// Accounts for the [`sell` handler](reward_center/fn.sell.html).
@@ -50,8 +52,9 @@ pub struct CreateListing<'info> {
     )]
     pub listing: Box<Account<'info, Listing>>,
 
-    /// The auctioneer program PDA running this auction.
+    /// The auctioneer program PDA running this auction, writable to count the listing as open.
     #[account(
+        mut,
         has_one = auction_house,
         seeds = [
             REWARD_CENTER.as_bytes(),
@@ -185,6 +188,7 @@ pub fn handler<'info>(
         free_trade_state_bump,
         program_as_signer_bump,
         price,
+        expires_at,
     }: CreateListingParams,
 ) -> Result<()> {
     let metadata = &ctx.accounts.metadata;
@@ -192,13 +196,28 @@ pub fn handler<'info>(
     let auction_house = &ctx.accounts.auction_house;
     let token_account = &ctx.accounts.token_account;
 
+    require!(
+        !reward_center.is_paused(PauseFlags::LISTINGS),
+        RewardCenterError::ListingsPaused
+    );
+
     assert_metadata_valid(metadata, token_account)?;
 
     let wallet = &ctx.accounts.wallet;
     let clock = Clock::get()?;
+
+    if let Some(expires_at) = expires_at {
+        require_gt!(
+            expires_at,
+            clock.unix_timestamp,
+            RewardCenterError::ExpiryInvalid
+        );
+    }
+
     let listing = &mut ctx.accounts.listing;
     let auction_house_key = auction_house.key();
 
+    listing.version = Listing::VERSION;
     listing.reward_center = reward_center.key();
     listing.seller = wallet.key();
     listing.metadata = metadata.key();
@@ -209,6 +228,8 @@ pub fn handler<'info>(
         .get(LISTING)
         .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
     listing.created_at = clock.unix_timestamp;
+    listing.updated_at = clock.unix_timestamp;
+    listing.expires_at = expires_at;
 
     let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
         REWARD_CENTER.as_bytes(),
@@ -254,5 +275,23 @@ pub fn handler<'info>(
         reward_center_signer_seeds,
     )?;
 
+    let listing = &ctx.accounts.listing;
+
+    emit!(ListingCreated {
+        reward_center: listing.reward_center,
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        price: listing.price,
+        token_size: listing.token_size,
+        expires_at: listing.expires_at,
+    });
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.open_listings = reward_center
+        .open_listings
+        .checked_add(1)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
     Ok(())
 }
@@ -1,4 +1,5 @@
 pub mod buy;
 pub mod close;
+pub mod close_expired;
 pub mod create;
 pub mod update;
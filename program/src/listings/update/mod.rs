@@ -8,7 +8,8 @@ use mtly_auction_house::{
 use crate::{
     constants::{LISTING, REWARD_CENTER},
     errors::RewardCenterError,
-    state::{Listing, RewardCenter},
+    events::ListingUpdated,
+    state::{Listing, PauseFlags, RewardCenter},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -84,10 +85,28 @@ pub fn handler(
     let listing = &mut ctx.accounts.listing;
     let metadata = &ctx.accounts.metadata;
     let token_account = &ctx.accounts.token_account;
+    let reward_center = &ctx.accounts.reward_center;
+
+    require!(
+        !reward_center.is_paused(PauseFlags::LISTINGS),
+        RewardCenterError::ListingsPaused
+    );
 
     assert_metadata_valid(metadata, token_account)?;
 
+    let previous_price = listing.price;
+    listing.version = Listing::VERSION;
     listing.price = new_price;
+    listing.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ListingUpdated {
+        reward_center: listing.reward_center,
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        previous_price,
+        price: new_price,
+    });
 
     Ok(())
 }
@@ -18,6 +18,7 @@ use solana_program::system_program;
 use crate::{
     constants::{OFFER, REWARD_CENTER},
     errors::RewardCenterError,
+    events::OfferClosed,
     id,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
     state::{Offer, RewardCenter},
@@ -77,9 +78,9 @@ pub struct CloseOffer<'info> {
     /// Auction House authority account.
     pub authority: UncheckedAccount<'info>,
 
-    /// CHECK: Verified in ah_auctioneer_pda seeds and in bid logic.
-    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    /// The auctioneer program PDA running this auction, writable to stop counting the offer.
     #[account(
+        mut,
         has_one = auction_house,
         seeds = [
             REWARD_CENTER.as_bytes(),
@@ -257,6 +258,17 @@ pub fn handler(
     offer_account_info.assign(&system_program::id());
     offer_account_info.realloc(0, false)?;
 
+    emit!(OfferClosed {
+        reward_center: offer.reward_center,
+        offer: offer_account_info.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        expired: false,
+    });
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.open_offers = reward_center.open_offers.saturating_sub(1);
+
     Ok(())
 }
 
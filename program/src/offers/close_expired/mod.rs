@@ -0,0 +1,265 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mtly_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::{AuctioneerCancel, AuctioneerWithdraw},
+    instruction::{
+        AuctioneerCancel as AuctioneerCancelParams, AuctioneerWithdraw as AuctioneerWithdrawParams,
+    },
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+
+use crate::{
+    constants::{OFFER, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::OfferClosed,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    offers::close::CloseOfferParams,
+    state::{Offer, RewardCenter},
+};
+use solana_program::program::invoke_signed;
+
+#[derive(Accounts, Clone)]
+#[instruction(close_offer_params: CloseOfferParams)]
+pub struct CloseExpiredOffer<'info> {
+    /// Any wallet cranking the expired offer.
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validated against the offer buyer.
+    /// Buyer wallet account receiving the offer rent.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// The Offer config account used for bids
+    #[account(
+        mut,
+        has_one = buyer,
+        seeds = [
+            OFFER.as_bytes(),
+            buyer.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = offer.bump,
+        close = buyer
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account containing the token of the sale to be canceled.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in auction house program withdraw_logic.
+    /// SPL token account or native SOL account of the buyer to transfer funds to. If the account is a native SOL account, this is the same as the buyer address.
+    #[account(mut)]
+    pub receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = close_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: assertion with mtly_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Token mint account of SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer program PDA running this auction, writable to stop counting the offer.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program cancel_logic.
+    /// Trade state PDA account representing the bid to be canceled.
+    #[account(mut)]
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program cancel_logic.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CloseExpiredOffer>,
+    CloseOfferParams {
+        escrow_payment_bump,
+    }: CloseOfferParams,
+) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house = &ctx.accounts.auction_house;
+    let metadata = &ctx.accounts.metadata;
+    let token_account = &ctx.accounts.token_account;
+    let buyer = &ctx.accounts.buyer;
+    let offer = &ctx.accounts.offer;
+    let token_size = offer.token_size;
+    let buyer_price = offer.price;
+    let price = offer.price_with_fees;
+    let auction_house_key = auction_house.key();
+
+    require!(
+        offer.is_expired(Clock::get()?.unix_timestamp),
+        RewardCenterError::OfferNotExpired
+    );
+
+    assert_metadata_valid(metadata, token_account)?;
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    // Withdraw offer funds back to the buyer via invoke_signed
+    let withdraw_offer_ctx_accounts = AuctioneerWithdraw {
+        wallet: buyer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        receipt_account: ctx.accounts.receipt_account.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+    };
+
+    let withdraw_offer_params = AuctioneerWithdrawParams {
+        escrow_payment_bump,
+        amount: price,
+    };
+
+    let (withdraw_offer_ix, withdraw_offer_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: withdraw_offer_ctx_accounts,
+            instruction_data: withdraw_offer_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &withdraw_offer_ix,
+        &withdraw_offer_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    // Cancel (Close Offer) instruction via invoke_signed
+    let cancel_offer_ctx_accounts = AuctioneerCancel {
+        wallet: buyer.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        trade_state: ctx.accounts.trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let close_offer_params = AuctioneerCancelParams {
+        buyer_price,
+        token_size,
+    };
+
+    let (cancel_offer_ix, cancel_offer_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: cancel_offer_ctx_accounts,
+            instruction_data: close_offer_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &cancel_offer_ix,
+        &cancel_offer_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    emit!(OfferClosed {
+        reward_center: offer.reward_center,
+        offer: offer.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        expired: true,
+    });
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.open_offers = reward_center.open_offers.saturating_sub(1);
+
+    Ok(())
+}
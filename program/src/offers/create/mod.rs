@@ -1,7 +1,8 @@
 use crate::{
     constants::{OFFER, REWARD_CENTER},
     errors::RewardCenterError,
-    state::{Offer, RewardCenter},
+    events::OfferCreated,
+    state::{Offer, PauseFlags, RewardCenter},
 };
 use anchor_lang::prelude::{Result, *};
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -20,6 +21,7 @@ pub struct CreateOfferParams {
     pub escrow_payment_bump: u8,
     pub buyer_price: u64,
     pub token_size: u64,
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Accounts, Clone)]
@@ -76,8 +78,9 @@ pub struct CreateOffer<'info> {
     /// Auction House authority account.
     pub authority: UncheckedAccount<'info>,
 
-    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    /// The auctioneer program PDA running this auction, writable to count the offer as open.
     #[account(
+        mut,
         has_one = auction_house,
         seeds = [
             REWARD_CENTER.as_bytes(),
@@ -157,7 +160,7 @@ pub fn handler(
         escrow_payment_bump,
         buyer_price,
         token_size,
-        ..
+        expires_at,
     }: CreateOfferParams,
 ) -> Result<()> {
     let metadata = &ctx.accounts.metadata;
@@ -166,10 +169,25 @@ pub fn handler(
     let token_account = &ctx.accounts.token_account;
     let wallet = &ctx.accounts.wallet;
     let clock = Clock::get()?;
+
+    require!(
+        !reward_center.is_paused(PauseFlags::OFFERS),
+        RewardCenterError::OffersPaused
+    );
+
+    if let Some(expires_at) = expires_at {
+        require_gt!(
+            expires_at,
+            clock.unix_timestamp,
+            RewardCenterError::ExpiryInvalid
+        );
+    }
+
     let offer = &mut ctx.accounts.offer;
 
     let auction_house_key = auction_house.key();
 
+    offer.version = Offer::VERSION;
     offer.reward_center = reward_center.key();
     offer.buyer = wallet.key();
     offer.metadata = metadata.key();
@@ -180,6 +198,8 @@ pub fn handler(
         .get(OFFER)
         .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
     offer.created_at = clock.unix_timestamp;
+    offer.updated_at = clock.unix_timestamp;
+    offer.expires_at = expires_at;
 
     let md = Box::new(Metadata::from_account_info(&metadata.to_account_info())?);
 
@@ -250,5 +270,24 @@ pub fn handler(
         Some(price),
     )?;
 
+    let offer = &ctx.accounts.offer;
+
+    emit!(OfferCreated {
+        reward_center: offer.reward_center,
+        offer: offer.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        price: offer.price,
+        price_with_fees: offer.price_with_fees,
+        token_size: offer.token_size,
+        expires_at: offer.expires_at,
+    });
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.open_offers = reward_center
+        .open_offers
+        .checked_add(1)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
     Ok(())
 }
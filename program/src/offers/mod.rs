@@ -1,3 +1,5 @@
 pub mod accept;
 pub mod close;
+pub mod close_expired;
 pub mod create;
+pub mod update;
@@ -0,0 +1,373 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use mtly_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::{AuctioneerCancel, AuctioneerDeposit, AuctioneerPublicBuy, AuctioneerWithdraw},
+    instruction::{
+        AuctioneerCancel as AuctioneerCancelParams, AuctioneerWithdraw as AuctioneerWithdrawParams,
+    },
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+use solana_program::program::invoke_signed;
+
+use crate::{
+    constants::{OFFER, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::OfferUpdated,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    state::{Offer, PauseFlags, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOfferParams {
+    pub trade_state_bump: u8,
+    pub escrow_payment_bump: u8,
+    pub new_price: u64,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(update_offer_params: UpdateOfferParams)]
+pub struct UpdateOffer<'info> {
+    /// Buyer wallet
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The Offer config account used for bids
+    #[account(
+        mut,
+        has_one = metadata,
+        has_one = reward_center,
+        seeds = [
+            OFFER.as_bytes(),
+            wallet.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = offer.bump
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// CHECK: Validated in deposit_logic.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in deposit_logic.
+    pub transfer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program withdraw_logic.
+    /// SPL token account or native SOL account to transfer funds to. If the account is a native SOL account, this is the same as the wallet address.
+    #[account(mut)]
+    pub receipt_account: UncheckedAccount<'info>,
+
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account containing the token the offer is placed on.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: assertion with mtly_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = update_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program cancel_logic.
+    /// Trade state PDA account representing the bid to be replaced.
+    #[account(mut)]
+    pub old_buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Trade state PDA account representing the bid at the new price.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_account.mint.as_ref(),
+            update_offer_params.new_price.to_le_bytes().as_ref(),
+            offer.token_size.to_le_bytes().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = update_offer_params.trade_state_bump
+    )]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateOffer>,
+    UpdateOfferParams {
+        trade_state_bump,
+        escrow_payment_bump,
+        new_price,
+    }: UpdateOfferParams,
+) -> Result<()> {
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let reward_center = &ctx.accounts.reward_center;
+    let token_account = &ctx.accounts.token_account;
+    let offer = &ctx.accounts.offer;
+
+    require!(
+        !reward_center.is_paused(PauseFlags::OFFERS),
+        RewardCenterError::OffersPaused
+    );
+
+    let token_size = offer.token_size;
+    let old_price = offer.price;
+    // The escrow is only adjusted by the delta when the amount it holds for the offer is known
+    let old_price_with_fees = offer
+        .escrowed_price_with_fees()
+        .ok_or(RewardCenterError::OfferEscrowUnknown)?;
+    let auction_house_key = auction_house.key();
+
+    require_gt!(new_price, 0, RewardCenterError::PriceInvalid);
+    require!(
+        !offer.is_expired(Clock::get()?.unix_timestamp),
+        RewardCenterError::OfferExpired
+    );
+
+    assert_metadata_valid(metadata, token_account)?;
+
+    let md = Box::new(Metadata::from_account_info(&metadata.to_account_info())?);
+
+    let new_price_with_fees = new_price
+        + ((auction_house.seller_fee_basis_points as u64 * new_price) / 10000)
+        + ((md.data.seller_fee_basis_points as u64 * new_price) / 10000);
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    // Cancel the bid at the old price via invoke_signed
+    let cancel_offer_ctx_accounts = AuctioneerCancel {
+        wallet: ctx.accounts.wallet.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        trade_state: ctx.accounts.old_buyer_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let cancel_offer_params = AuctioneerCancelParams {
+        buyer_price: old_price,
+        token_size,
+    };
+
+    let (cancel_offer_ix, cancel_offer_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: cancel_offer_ctx_accounts,
+            instruction_data: cancel_offer_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &cancel_offer_ix,
+        &cancel_offer_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    // Only move the difference in and out of escrow
+    if new_price_with_fees > old_price_with_fees {
+        let deposit_accounts_ctx = CpiContext::new_with_signer(
+            ctx.accounts.auction_house_program.to_account_info(),
+            AuctioneerDeposit {
+                wallet: ctx.accounts.wallet.to_account_info(),
+                transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+                treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+                ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                auction_house: ctx.accounts.auction_house.to_account_info(),
+                auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+                escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+                payment_account: ctx.accounts.payment_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            reward_center_signer_seeds,
+        );
+
+        mtly_auction_house::cpi::auctioneer_deposit(
+            deposit_accounts_ctx,
+            escrow_payment_bump,
+            new_price_with_fees - old_price_with_fees,
+        )?;
+    } else if new_price_with_fees < old_price_with_fees {
+        let withdraw_offer_ctx_accounts = AuctioneerWithdraw {
+            wallet: ctx.accounts.wallet.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            ata_program: ctx.accounts.ata_program.to_account_info(),
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+            receipt_account: ctx.accounts.receipt_account.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        };
+
+        let withdraw_offer_params = AuctioneerWithdrawParams {
+            escrow_payment_bump,
+            amount: old_price_with_fees - new_price_with_fees,
+        };
+
+        let (withdraw_offer_ix, withdraw_offer_account_infos) =
+            make_auctioneer_instruction(AuctioneerInstructionArgs {
+                accounts: withdraw_offer_ctx_accounts,
+                instruction_data: withdraw_offer_params.data(),
+                auctioneer_authority: ctx.accounts.reward_center.key(),
+                remaining_accounts: None,
+            });
+
+        invoke_signed(
+            &withdraw_offer_ix,
+            &withdraw_offer_account_infos,
+            reward_center_signer_seeds,
+        )?;
+    }
+
+    let public_buy_accounts_ctx = CpiContext::new_with_signer(
+        ctx.accounts.auction_house_program.to_account_info(),
+        AuctioneerPublicBuy {
+            wallet: ctx.accounts.wallet.to_account_info(),
+            payment_account: ctx.accounts.payment_account.to_account_info(),
+            transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+            treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        reward_center_signer_seeds,
+    );
+
+    mtly_auction_house::cpi::auctioneer_public_buy(
+        public_buy_accounts_ctx,
+        trade_state_bump,
+        escrow_payment_bump,
+        new_price,
+        token_size,
+        Some(new_price_with_fees),
+    )?;
+
+    let offer = &mut ctx.accounts.offer;
+    offer.version = Offer::VERSION;
+    offer.price = new_price;
+    offer.price_with_fees = new_price_with_fees;
+    offer.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(OfferUpdated {
+        reward_center: offer.reward_center,
+        offer: offer.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        previous_price: old_price,
+        price: new_price,
+        price_with_fees: new_price_with_fees,
+    });
+
+    Ok(())
+}
@@ -6,9 +6,40 @@ pub fn find_reward_center_address(auction_house: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[REWARD_CENTER.as_bytes(), auction_house.as_ref()], &id())
 }
 
-pub fn find_purchase_ticket_address(listing: &Pubkey, offer: &Pubkey) -> (Pubkey, u8) {
+pub fn find_purchase_ticket_address(
+    listing: &Pubkey,
+    offer: &Pubkey,
+    sale_number: u64,
+) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[PURCHASE_TICKET.as_bytes(), listing.as_ref(), offer.as_ref()],
+        &[
+            PURCHASE_TICKET.as_bytes(),
+            listing.as_ref(),
+            offer.as_ref(),
+            &sale_number.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_sale_record_address(reward_center: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SALE_RECORD.as_bytes(),
+            reward_center.as_ref(),
+            mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_sale_counter_address(reward_center: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SALE_COUNTER.as_bytes(),
+            reward_center.as_ref(),
+            mint.as_ref(),
+        ],
         &id(),
     )
 }
@@ -44,3 +75,72 @@ pub fn find_offer_address(
         &id(),
     )
 }
+
+pub fn find_rewardable_collection_address(
+    reward_center: &Pubkey,
+    collection: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REWARDABLE_COLLECTION.as_bytes(),
+            reward_center.as_ref(),
+            collection.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_reward_debt_address(reward_center: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REWARD_DEBT.as_bytes(),
+            reward_center.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_wallet_reward_window_address(reward_center: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WALLET_REWARD_WINDOW.as_bytes(),
+            reward_center.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_reward_vesting_address(reward_center: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REWARD_VESTING.as_bytes(),
+            reward_center.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_attributor_address(reward_center: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ATTRIBUTOR.as_bytes(),
+            reward_center.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_sponsor_pool_address(reward_center: &Pubkey, collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SPONSOR_POOL.as_bytes(),
+            reward_center.as_ref(),
+            collection.as_ref(),
+        ],
+        &id(),
+    )
+}
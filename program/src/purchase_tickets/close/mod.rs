@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PurchaseTicket;
+
+/// Accounts for the [`close_purchase_ticket` handler](reward_center/fn.close_purchase_ticket.html).
+#[derive(Accounts, Clone)]
+pub struct ClosePurchaseTicket<'info> {
+    /// The wallet that funded the purchase ticket, receiving its rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The purchase ticket of the sale.
+    #[account(
+        mut,
+        has_one = payer,
+        close = payer
+    )]
+    pub purchase_ticket: Account<'info, PurchaseTicket>,
+}
+
+pub fn handler(_ctx: Context<ClosePurchaseTicket>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,108 @@
+pub mod close;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PURCHASE_TICKET, SALE_COUNTER},
+    errors::RewardCenterError,
+    state::{PurchaseTicket, SaleCounter},
+    utils::create_pda_account,
+};
+
+/// Accounts used to record the purchase ticket of a sale.
+pub struct PurchaseTicketRecord<'info> {
+    pub purchase_ticket: AccountInfo<'info>,
+    pub purchase_ticket_bump: u8,
+    /// counter of the sales of the NFT, numbering its purchase tickets
+    pub sale_counter: AccountInfo<'info>,
+    pub sale_counter_bump: u8,
+    pub reward_center: Pubkey,
+    /// mint of the NFT sold
+    pub mint: Pubkey,
+    /// listing address of the seller for the NFT, whether or not the sale went through it
+    pub listing: Pubkey,
+    /// offer address of the buyer for the NFT, whether or not the sale went through it
+    pub offer: Pubkey,
+    /// signer funding the purchase ticket, and the sale counter when it has to be created
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> PurchaseTicketRecord<'info> {
+    /// Records the sale on a new purchase ticket numbered by the sale counter of the NFT, filling
+    /// in its bump, payer and sale number. The sale counter is created on the first sale.
+    pub fn record(self, mut purchase_ticket: PurchaseTicket) -> Result<()> {
+        if self.sale_counter.data_is_empty() {
+            create_pda_account(
+                &self.sale_counter,
+                SaleCounter::size(),
+                &self.payer,
+                &self.system_program,
+                &[
+                    SALE_COUNTER.as_bytes(),
+                    self.reward_center.as_ref(),
+                    self.mint.as_ref(),
+                    &[self.sale_counter_bump],
+                ],
+            )?;
+
+            SaleCounter {
+                reward_center: self.reward_center,
+                mint: self.mint,
+                sale_count: 0,
+                bump: self.sale_counter_bump,
+            }
+            .try_serialize(&mut &mut self.sale_counter.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut sale_counter =
+            SaleCounter::try_deserialize(&mut &self.sale_counter.try_borrow_data()?[..])?;
+
+        let sale_number = sale_counter.sale_count;
+
+        let purchase_ticket_seeds: &[&[u8]] = &[
+            PURCHASE_TICKET.as_bytes(),
+            self.listing.as_ref(),
+            self.offer.as_ref(),
+            &sale_number.to_le_bytes(),
+            &[self.purchase_ticket_bump],
+        ];
+
+        let purchase_ticket_address =
+            Pubkey::create_program_address(purchase_ticket_seeds, &crate::id())
+                .map_err(|_| RewardCenterError::PurchaseTicketMismatch)?;
+
+        require_keys_eq!(
+            self.purchase_ticket.key(),
+            purchase_ticket_address,
+            RewardCenterError::PurchaseTicketMismatch
+        );
+        require!(
+            self.purchase_ticket.data_is_empty(),
+            RewardCenterError::PurchaseTicketMismatch
+        );
+
+        create_pda_account(
+            &self.purchase_ticket,
+            PurchaseTicket::size(),
+            &self.payer,
+            &self.system_program,
+            purchase_ticket_seeds,
+        )?;
+
+        purchase_ticket.payer = self.payer.key();
+        purchase_ticket.sale_number = sale_number;
+        purchase_ticket.bump = self.purchase_ticket_bump;
+
+        purchase_ticket.try_serialize(&mut &mut self.purchase_ticket.try_borrow_mut_data()?[..])?;
+
+        sale_counter.sale_count = sale_counter
+            .sale_count
+            .checked_add(1)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        sale_counter.try_serialize(&mut &mut self.sale_counter.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::REWARD_CENTER, errors::RewardCenterError, state::RewardCenter,
+    utils::clear_account_data,
+};
+
+/// Accounts for the [`accept_authority` handler](reward_center/fn.accept_authority.html).
+#[derive(Accounts, Clone)]
+pub struct AcceptAuthority<'info> {
+    /// The wallet proposed as the new reward center authority.
+    #[
+      account(
+        constraint = reward_center.pending_authority == Some(new_authority.key()) @ RewardCenterError::PendingAuthorityMismatch
+      )
+    ]
+    pub new_authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Account<'info, RewardCenter>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    // Clearing the pending authority shortens the account layout
+    clear_account_data(&reward_center.to_account_info())?;
+
+    reward_center.authority = ctx.accounts.new_authority.key();
+    reward_center.pending_authority = None;
+
+    msg!(
+        "Transferred reward center authority to {}",
+        reward_center.authority
+    );
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{close_account, transfer, CloseAccount, Token, TokenAccount, Transfer};
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::REWARD_CENTER, errors::RewardCenterError, events::RewardCenterTreasuryWithdrawn,
+    state::RewardCenter,
+};
+
+/// Options for closing the reward center
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CloseRewardCenterParams {
+    /// close the reward center even if it still has open listings or offers, or was upgraded
+    /// from a version that did not count them all
+    pub force: bool,
+}
+
+/// Accounts for the [`close_reward_center` handler](reward_center/fn.close_reward_center.html).
+#[derive(Accounts, Clone)]
+#[instruction(close_reward_center_params: CloseRewardCenterParams)]
+pub struct CloseRewardCenter<'info> {
+    /// The reward center authority, receiving the rent of the closed accounts.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    // Reward center reward token account
+    #[account(
+        mut,
+        constraint = reward_center_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = reward_center_reward_token_account.owner == reward_center.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub reward_center_reward_token_account: Account<'info, TokenAccount>,
+
+    // Token account chosen by the authority to receive the remaining rewards
+    #[account(
+        mut,
+        constraint = destination_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub destination_reward_token_account: Account<'info, TokenAccount>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        close = wallet,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Account<'info, RewardCenter>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<CloseRewardCenter>,
+    CloseRewardCenterParams { force }: CloseRewardCenterParams,
+) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let reward_center_reward_token_account = &ctx.accounts.reward_center_reward_token_account;
+    let destination_reward_token_account = &ctx.accounts.destination_reward_token_account;
+    let token_program = &ctx.accounts.token_program;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let remaining_amount = reward_center_reward_token_account.amount;
+
+    require!(
+        reward_center.outstanding_rewards == 0,
+        RewardCenterError::RewardCenterHasOutstandingRewards
+    );
+
+    if !force {
+        require!(
+            reward_center.open_listings == 0 && reward_center.open_offers == 0,
+            RewardCenterError::RewardCenterHasOpenOrders
+        );
+        // Orders and rewards predating their tracking can only be ruled out off chain
+        require!(
+            !reward_center.has_uncounted_obligations,
+            RewardCenterError::RewardCenterHasUncountedObligations
+        );
+    }
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    if remaining_amount > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: reward_center_reward_token_account.to_account_info(),
+                    to: destination_reward_token_account.to_account_info(),
+                    authority: reward_center.to_account_info(),
+                },
+                reward_center_signer_seeds,
+            ),
+            remaining_amount,
+        )?;
+
+        emit!(RewardCenterTreasuryWithdrawn {
+            reward_center_authority: ctx.accounts.wallet.key(),
+            destination_reward_token_account: destination_reward_token_account.key(),
+            rewards_mint: reward_center.token_mint,
+            withdrawal_amount: remaining_amount,
+        });
+    }
+
+    close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: reward_center_reward_token_account.to_account_info(),
+            destination: ctx.accounts.wallet.to_account_info(),
+            authority: reward_center.to_account_info(),
+        },
+        reward_center_signer_seeds,
+    ))?;
+
+    Ok(())
+}
@@ -81,13 +81,20 @@ pub fn handler(
     ctx: Context<CreateRewardCenter>,
     reward_center_params: CreateRewardCenterParams,
 ) -> Result<()> {
+    reward_center_params.reward_rules.validate()?;
+
     let mint = &ctx.accounts.mint;
     let auction_house = &ctx.accounts.auction_house;
     let reward_center = &mut ctx.accounts.reward_center;
 
+    reward_center.version = RewardCenter::VERSION;
     reward_center.token_mint = mint.key();
     reward_center.auction_house = auction_house.key();
+    reward_center.authority = auction_house.authority;
+    reward_center.pending_authority = None;
     reward_center.reward_rules = reward_center_params.reward_rules;
+    reward_center.treasury_mint_decimals = ctx.accounts.auction_house_treasury_mint.decimals;
+    reward_center.reward_mint_decimals = mint.decimals;
     reward_center.bump = *ctx
         .bumps
         .get(REWARD_CENTER)
@@ -6,6 +6,7 @@ use crate::{
     constants::REWARD_CENTER,
     errors::RewardCenterError,
     state::{RewardCenter, RewardRules},
+    utils::clear_account_data,
 };
 
 /// Options to set on the reward center
@@ -22,7 +23,7 @@ pub struct EditRewardCenter<'info> {
     #[
       account(
         mut,
-        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
       )
     ]
     pub wallet: Signer<'info>,
@@ -52,7 +53,19 @@ pub fn handler(
     ctx: Context<EditRewardCenter>,
     reward_center_params: EditRewardCenterParams,
 ) -> Result<()> {
+    reward_center_params.reward_rules.validate()?;
+
     let reward_center = &mut ctx.accounts.reward_center;
+
+    // Reward centers at an older version are upgraded by migrate_reward_center before changing
+    require_eq!(
+        reward_center.version,
+        RewardCenter::VERSION,
+        RewardCenterError::RewardCenterNotMigrated
+    );
+
+    clear_account_data(&reward_center.to_account_info())?;
+
     reward_center.reward_rules = reward_center_params.reward_rules;
 
     Ok(())
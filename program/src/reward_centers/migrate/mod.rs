@@ -0,0 +1,405 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    AnchorDeserialize, Discriminator,
+};
+use anchor_spl::token::Mint;
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::REWARD_CENTER,
+    errors::RewardCenterError,
+    id,
+    state::{
+        Listing, Offer, PayoutMode, PayoutOperation, RewardCenter, RewardCurve, RewardMode,
+        RewardRules,
+    },
+};
+
+/// Reward center layout before accounts were versioned.
+#[derive(AnchorDeserialize)]
+struct LegacyRewardCenter {
+    token_mint: Pubkey,
+    auction_house: Pubkey,
+    seller_reward_payout_basis_points: u16,
+    mathematical_operand: PayoutOperation,
+    payout_numeral: u16,
+    bump: u8,
+}
+
+/// Listing layout before accounts were versioned, optionally followed by `expires_at`.
+#[derive(AnchorDeserialize)]
+struct LegacyListing {
+    reward_center: Pubkey,
+    seller: Pubkey,
+    metadata: Pubkey,
+    price: u64,
+    token_size: u64,
+    bump: u8,
+    created_at: i64,
+}
+
+/// Offer layout before accounts were versioned, optionally followed by `price_with_fees` and `expires_at`.
+#[derive(AnchorDeserialize)]
+struct LegacyOffer {
+    reward_center: Pubkey,
+    buyer: Pubkey,
+    metadata: Pubkey,
+    price: u64,
+    token_size: u64,
+    bump: u8,
+    created_at: i64,
+}
+
+/// Accounts for the [`migrate_reward_center` handler](reward_center/fn.migrate_reward_center.html).
+///
+/// Listings and offers of the reward center to upgrade are passed as writable remaining accounts.
+#[derive(Accounts, Clone)]
+pub struct MigrateRewardCenter<'info> {
+    /// Pays the additional rent of the upgraded accounts.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Seeds checked in constraint. Deserialized in the handler as it may still use the legacy layout.
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub reward_center: UncheckedAccount<'info>,
+
+    /// The mint of the auction house treasury.
+    #[account(constraint = treasury_mint.key() == auction_house.treasury_mint @ RewardCenterError::AuctionHouseTreasuryMismatch)]
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// The mint of the reward token, checked against the reward center in the handler.
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateRewardCenter<'info>>) -> Result<()> {
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let reward_center_info = ctx.accounts.reward_center.to_account_info();
+    let reward_center_key = reward_center_info.key();
+
+    assert_program_account(&reward_center_info, &RewardCenter::discriminator())?;
+
+    // Reward centers created before versioning are smaller than any versioned layout
+    let mut reward_center = if reward_center_info.data_len() < RewardCenter::size() {
+        let legacy = LegacyRewardCenter::deserialize(&mut &reward_center_info.data.borrow()[8..])?;
+
+        require_keys_eq!(
+            ctx.accounts.reward_mint.key(),
+            legacy.token_mint,
+            RewardCenterError::MintMismatch
+        );
+
+        msg!(
+            "Reward center upgraded to version {}",
+            RewardCenter::VERSION
+        );
+
+        RewardCenter {
+            version: RewardCenter::VERSION,
+            token_mint: legacy.token_mint,
+            auction_house: legacy.auction_house,
+            authority: ctx.accounts.auction_house.authority,
+            pending_authority: None,
+            treasury_mint_decimals: ctx.accounts.treasury_mint.decimals,
+            reward_mint_decimals: ctx.accounts.reward_mint.decimals,
+            pause_state: 0,
+            open_listings: 0,
+            open_offers: 0,
+            bump: legacy.bump,
+            reward_rules: RewardRules {
+                seller_reward_payout_basis_points: legacy.seller_reward_payout_basis_points,
+                mathematical_operand: legacy.mathematical_operand,
+                payout_numeral: legacy.payout_numeral,
+                payout_mode: PayoutMode::Immediate,
+                attributor_reward_basis_points: 0,
+                collection_oracle: None,
+                maker_taker_split: None,
+                reward_curve: RewardCurve::Linear,
+                wash_trade_window_seconds: 0,
+                min_order_age_seconds: 0,
+                max_reward_per_sale: None,
+                max_reward_per_wallet_per_epoch: None,
+            },
+            candidate_reward_rules: None,
+            reward_mode: RewardMode::Live,
+            outstanding_rewards: 0,
+            // Legacy orders left out of the migration are not counted
+            has_uncounted_obligations: true,
+            reserved: [0; 64],
+        }
+    } else {
+        require_eq!(
+            reward_center_info.try_borrow_data()?[8],
+            RewardCenter::VERSION,
+            RewardCenterError::InvalidMigrationAccount
+        );
+
+        msg!(
+            "Reward center is already at version {}",
+            RewardCenter::VERSION
+        );
+
+        RewardCenter::try_deserialize(&mut &reward_center_info.try_borrow_data()?[..])?
+    };
+
+    // Legacy orders were never counted by the reward center, so they are counted as they upgrade
+    for account in ctx.remaining_accounts {
+        let discriminator: [u8; 8] = account
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|discriminator| discriminator.try_into().ok())
+            .ok_or(RewardCenterError::InvalidMigrationAccount)?;
+
+        if discriminator == Listing::discriminator() {
+            if migrate_listing(account, &reward_center_key, &payer, &system_program)? {
+                reward_center.open_listings = reward_center
+                    .open_listings
+                    .checked_add(1)
+                    .ok_or(RewardCenterError::NumericalOverflowError)?;
+            }
+        } else if discriminator == Offer::discriminator() {
+            if migrate_offer(account, &reward_center_key, &payer, &system_program)? {
+                reward_center.open_offers = reward_center
+                    .open_offers
+                    .checked_add(1)
+                    .ok_or(RewardCenterError::NumericalOverflowError)?;
+            }
+        } else {
+            return err!(RewardCenterError::InvalidMigrationAccount);
+        }
+    }
+
+    resize(
+        &reward_center_info,
+        RewardCenter::size(),
+        &payer,
+        &system_program,
+    )?;
+    write(&reward_center_info, &reward_center)?;
+
+    Ok(())
+}
+
+/// Upgrades a listing of the reward center, returning whether it predates versioning and was not
+/// counted as open yet.
+fn migrate_listing<'info>(
+    account: &AccountInfo<'info>,
+    reward_center: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<bool> {
+    assert_program_account(account, &Listing::discriminator())?;
+
+    if account.data_len() >= Listing::size() {
+        let mut listing = Listing::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            listing.reward_center,
+            *reward_center,
+            RewardCenterError::InvalidMigrationAccount
+        );
+
+        // updated_at was carved out of the reserved bytes in version 3, so earlier versions may
+        // hold anything there
+        if listing.version < Listing::VERSION {
+            listing.version = Listing::VERSION;
+            listing.updated_at = listing.created_at;
+            listing.reserved = [0; 24];
+
+            write(account, &listing)?;
+
+            msg!(
+                "Listing {} upgraded to version {}",
+                account.key(),
+                Listing::VERSION
+            );
+        }
+
+        return Ok(false);
+    }
+
+    let data = account.data.borrow();
+    let mut legacy_data = &data[8..];
+    let legacy = LegacyListing::deserialize(&mut legacy_data)?;
+    let expires_at = Option::<i64>::deserialize(&mut legacy_data).unwrap_or_default();
+    drop(data);
+
+    require_keys_eq!(
+        legacy.reward_center,
+        *reward_center,
+        RewardCenterError::InvalidMigrationAccount
+    );
+
+    let listing = Listing {
+        version: Listing::VERSION,
+        reward_center: legacy.reward_center,
+        seller: legacy.seller,
+        metadata: legacy.metadata,
+        price: legacy.price,
+        token_size: legacy.token_size,
+        bump: legacy.bump,
+        created_at: legacy.created_at,
+        expires_at,
+        updated_at: legacy.created_at,
+        reserved: [0; 24],
+    };
+
+    resize(account, Listing::size(), payer, system_program)?;
+    write(account, &listing)?;
+
+    msg!(
+        "Listing {} upgraded to version {}",
+        account.key(),
+        Listing::VERSION
+    );
+
+    Ok(true)
+}
+
+/// Upgrades a offer of the reward center, returning whether it predates versioning and was not
+/// counted as open yet.
+fn migrate_offer<'info>(
+    account: &AccountInfo<'info>,
+    reward_center: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<bool> {
+    assert_program_account(account, &Offer::discriminator())?;
+
+    if account.data_len() >= Offer::size() {
+        let mut offer = Offer::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            offer.reward_center,
+            *reward_center,
+            RewardCenterError::InvalidMigrationAccount
+        );
+
+        // updated_at was carved out of the reserved bytes in version 3, so earlier versions may
+        // hold anything there
+        if offer.version < Offer::VERSION {
+            offer.version = Offer::VERSION;
+            offer.updated_at = offer.created_at;
+            offer.reserved = [0; 24];
+
+            write(account, &offer)?;
+
+            msg!(
+                "Offer {} upgraded to version {}",
+                account.key(),
+                Offer::VERSION
+            );
+        }
+
+        return Ok(false);
+    }
+
+    let data = account.data.borrow();
+    let mut legacy_data = &data[8..];
+    let legacy = LegacyOffer::deserialize(&mut legacy_data)?;
+    let price_with_fees = u64::deserialize(&mut legacy_data).unwrap_or_default();
+    let expires_at = Option::<i64>::deserialize(&mut legacy_data).unwrap_or_default();
+    drop(data);
+
+    require_keys_eq!(
+        legacy.reward_center,
+        *reward_center,
+        RewardCenterError::InvalidMigrationAccount
+    );
+
+    let offer = Offer {
+        version: Offer::VERSION,
+        reward_center: legacy.reward_center,
+        buyer: legacy.buyer,
+        metadata: legacy.metadata,
+        price: legacy.price,
+        token_size: legacy.token_size,
+        bump: legacy.bump,
+        created_at: legacy.created_at,
+        price_with_fees,
+        expires_at,
+        updated_at: legacy.created_at,
+        reserved: [0; 24],
+    };
+
+    resize(account, Offer::size(), payer, system_program)?;
+    write(account, &offer)?;
+
+    msg!(
+        "Offer {} upgraded to version {}",
+        account.key(),
+        Offer::VERSION
+    );
+
+    Ok(true)
+}
+
+/// Checks that the account is owned by the program and holds the expected account type.
+fn assert_program_account(account: &AccountInfo, discriminator: &[u8; 8]) -> Result<()> {
+    require_keys_eq!(*account.owner, id(), ErrorCode::AccountOwnedByWrongProgram);
+    require!(
+        account.try_borrow_data()?.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    Ok(())
+}
+
+/// Grows the account to the given size, topping up its rent from the payer.
+fn resize<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account.lamports());
+
+    if rent_shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+
+    account.realloc(size, true)?;
+
+    Ok(())
+}
+
+/// Overwrites the account data with the upgraded account, zeroing any leftover bytes.
+fn write<T: AccountSerialize>(account: &AccountInfo, upgraded: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    upgraded.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
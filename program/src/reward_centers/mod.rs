@@ -1,2 +1,8 @@
+pub mod accept_authority;
+pub mod close;
 pub mod create;
 pub mod edit;
+pub mod migrate;
+pub mod propose_authority;
+pub mod set_pause_state;
+pub mod set_reward_mode;
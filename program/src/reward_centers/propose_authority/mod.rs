@@ -0,0 +1,58 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{constants::REWARD_CENTER, errors::RewardCenterError, state::RewardCenter};
+
+/// The wallet to propose as the new reward center authority
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ProposeAuthorityParams {
+    pub new_authority: Pubkey,
+}
+
+/// Accounts for the [`propose_authority` handler](reward_center/fn.propose_authority.html).
+#[derive(Accounts, Clone)]
+#[instruction(propose_authority_params: ProposeAuthorityParams)]
+pub struct ProposeAuthority<'info> {
+    /// The current reward center authority.
+    #[
+      account(
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Account<'info, RewardCenter>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    ProposeAuthorityParams { new_authority }: ProposeAuthorityParams,
+) -> Result<()> {
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    reward_center.pending_authority = Some(new_authority);
+
+    msg!("Proposed {} as reward center authority", new_authority);
+
+    Ok(())
+}
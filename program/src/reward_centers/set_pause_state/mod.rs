@@ -0,0 +1,67 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::REWARD_CENTER,
+    errors::RewardCenterError,
+    state::{PauseFlags, RewardCenter},
+};
+
+/// The set of [`PauseFlags`] to apply to the reward center
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetPauseStateParams {
+    pub pause_state: u8,
+}
+
+/// Accounts for the [`set_pause_state` handler](reward_center/fn.set_pause_state.html).
+#[derive(Accounts, Clone)]
+#[instruction(set_pause_state_params: SetPauseStateParams)]
+pub struct SetPauseState<'info> {
+    /// The reward center authority.
+    #[
+      account(
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Account<'info, RewardCenter>,
+}
+
+pub fn handler(
+    ctx: Context<SetPauseState>,
+    SetPauseStateParams { pause_state }: SetPauseStateParams,
+) -> Result<()> {
+    require!(
+        pause_state & !PauseFlags::ALL == 0,
+        RewardCenterError::InvalidPauseState
+    );
+
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    reward_center.pause_state = pause_state;
+
+    msg!("Reward center pause state set to {:#06b}", pause_state);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::REWARD_CENTER,
+    errors::RewardCenterError,
+    state::{RewardCenter, RewardMode, RewardRules},
+    utils::clear_account_data,
+};
+
+/// The [`RewardMode`] and candidate reward rules to apply to the reward center
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetRewardModeParams {
+    pub reward_mode: RewardMode,
+    pub candidate_reward_rules: Option<RewardRules>,
+}
+
+/// Accounts for the [`set_reward_mode` handler](reward_center/fn.set_reward_mode.html).
+#[derive(Accounts, Clone)]
+#[instruction(set_reward_mode_params: SetRewardModeParams)]
+pub struct SetRewardMode<'info> {
+    /// The reward center authority.
+    #[
+      account(
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Account<'info, RewardCenter>,
+}
+
+pub fn handler(
+    ctx: Context<SetRewardMode>,
+    SetRewardModeParams {
+        reward_mode,
+        candidate_reward_rules,
+    }: SetRewardModeParams,
+) -> Result<()> {
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    if let Some(candidate_reward_rules) = &candidate_reward_rules {
+        candidate_reward_rules.validate()?;
+
+        // Candidate payouts are evaluated for sales the reward rules reward, so a candidate
+        // oracle would never be consulted
+        require!(
+            candidate_reward_rules.collection_oracle.is_none(),
+            RewardCenterError::CandidateCollectionOracle
+        );
+    }
+
+    // Reward centers at an older version are upgraded by migrate_reward_center before changing
+    require_eq!(
+        reward_center.version,
+        RewardCenter::VERSION,
+        RewardCenterError::RewardCenterNotMigrated
+    );
+
+    clear_account_data(&reward_center.to_account_info())?;
+
+    reward_center.reward_mode = reward_mode;
+    reward_center.candidate_reward_rules = candidate_reward_rules;
+
+    msg!("Reward center reward mode set to {:?}", reward_mode);
+
+    Ok(())
+}
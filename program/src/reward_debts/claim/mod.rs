@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{REWARD_CENTER, REWARD_DEBT},
+    errors::RewardCenterError,
+    state::{PauseFlags, RewardCenter, RewardDebt},
+};
+
+/// Accounts for the [`claim_reward_debt` handler](reward_center/fn.claim_reward_debt.html).
+#[derive(Accounts, Clone)]
+pub struct ClaimRewardDebt<'info> {
+    /// Wallet owed the rewards.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The reward debt record of the wallet.
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = reward_center,
+        seeds = [
+            REWARD_DEBT.as_bytes(),
+            reward_center.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump = reward_debt.bump
+    )]
+    pub reward_debt: Account<'info, RewardDebt>,
+
+    /// The token account to receive the owed rewards.
+    #[account(
+        mut,
+        constraint = destination_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = destination_reward_token_account.owner == wallet.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub destination_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account holding the reward token for the reward center.
+    #[account(
+        mut,
+        constraint = reward_center_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = reward_center_reward_token_account.owner == reward_center.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The auctioneer program PDA running this auction, tracking the rewards it still owes.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimRewardDebt>) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    require!(
+        !reward_center.is_paused(PauseFlags::REWARDS),
+        RewardCenterError::RewardsPaused
+    );
+
+    let treasury_balance = ctx.accounts.reward_center_reward_token_account.amount;
+    let owed_amount = ctx.accounts.reward_debt.amount;
+
+    require_gt!(treasury_balance, 0, RewardCenterError::InsufficientFunds);
+
+    // Pay out as much of the debt as the treasury can currently cover
+    let claim_amount = owed_amount.min(treasury_balance);
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                authority: ctx.accounts.reward_center.to_account_info(),
+                from: ctx
+                    .accounts
+                    .reward_center_reward_token_account
+                    .to_account_info(),
+                to: ctx
+                    .accounts
+                    .destination_reward_token_account
+                    .to_account_info(),
+            },
+            reward_center_signer_seeds,
+        ),
+        claim_amount,
+    )?;
+
+    let reward_debt = &mut ctx.accounts.reward_debt;
+    reward_debt.amount = owed_amount
+        .checked_sub(claim_amount)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    let reward_center = &mut ctx.accounts.reward_center;
+    reward_center.outstanding_rewards = reward_center
+        .outstanding_rewards
+        .saturating_sub(claim_amount);
+
+    if reward_debt.amount == 0 {
+        reward_debt.close(ctx.accounts.wallet.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{REWARDABLE_COLLECTION, REWARD_CENTER},
+    errors::RewardCenterError,
+    state::{RewardCenter, RewardableCollection},
+};
+
+/// Accounts for the [`add_rewardable_collection` handler](reward_center/fn.add_rewardable_collection.html).
+#[derive(Accounts, Clone)]
+pub struct AddRewardableCollection<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The mint of the Metaplex collection NFT to reward.
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// The record marking the collection as eligible for rewards.
+    #[account(
+        init,
+        payer = wallet,
+        space = RewardableCollection::size(),
+        seeds = [
+            REWARDABLE_COLLECTION.as_bytes(),
+            reward_center.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub rewardable_collection: Account<'info, RewardableCollection>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddRewardableCollection>) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let collection_mint = &ctx.accounts.collection_mint;
+    let rewardable_collection = &mut ctx.accounts.rewardable_collection;
+
+    rewardable_collection.reward_center = reward_center.key();
+    rewardable_collection.collection = collection_mint.key();
+    rewardable_collection.bump = *ctx
+        .bumps
+        .get(REWARDABLE_COLLECTION)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -0,0 +1,726 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, TokenAccount, Transfer};
+use mpl_token_metadata::state::Metadata;
+
+use crate::{
+    constants::{REWARD_CENTER, REWARD_DEBT, REWARD_VESTING},
+    errors::RewardCenterError,
+    events::{
+        RewardPayouts, RewardShortfall, RewardsEvaluated, RewardsWithheld, RewardsWithheldReason,
+        SaleAttributed, SaleExecuted,
+    },
+    purchase_tickets::PurchaseTicketRecord,
+    sale_records::SaleRecordUpdate,
+    sponsor_pools::SponsorPoolPayout,
+    state::{
+        Attributor, PauseFlags, PayoutMode, PurchaseTicket, RewardCenter, RewardDebt, RewardMode,
+        RewardVesting, SalePath, SponsorPool,
+    },
+    utils::create_pda_account,
+    wallet_reward_windows::WalletRewardWindowUpdate,
+};
+
+/// Accounts used to pay a reward to the buyer or the seller of a sale.
+pub struct RewardPayout<'a, 'info> {
+    pub reward_center: &'a Account<'info, RewardCenter>,
+    pub reward_center_reward_token_account: &'a mut Account<'info, TokenAccount>,
+    /// wallet receiving the reward
+    pub wallet: Pubkey,
+    pub reward_token_account: AccountInfo<'info>,
+    /// only needed in immediate payout mode when the treasury cannot cover the reward
+    pub reward_debt: Option<AccountInfo<'info>>,
+    pub reward_debt_bump: Option<u8>,
+    /// only needed in vested payout mode
+    pub reward_vesting: Option<AccountInfo<'info>>,
+    pub reward_vesting_bump: Option<u8>,
+    /// signer funding the reward debt or vesting account when it has to be created
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> RewardPayout<'a, 'info> {
+    /// Pays the reward according to the payout mode of the reward center. Returns whether the
+    /// transfer was skipped and recorded as reward debt because the treasury could not cover it.
+    pub fn pay(self, amount: u64, reward_center_signer_seeds: &[&[&[u8]]]) -> Result<bool> {
+        if amount == 0 {
+            return Ok(false);
+        }
+
+        match self.reward_center.reward_rules.payout_mode {
+            PayoutMode::Immediate => {
+                self.reward_center_reward_token_account.reload()?;
+
+                if self.reward_center_reward_token_account.amount >= amount {
+                    transfer(
+                        CpiContext::new_with_signer(
+                            self.token_program.clone(),
+                            Transfer {
+                                authority: self.reward_center.to_account_info(),
+                                from: self.reward_center_reward_token_account.to_account_info(),
+                                to: self.reward_token_account.clone(),
+                            },
+                            reward_center_signer_seeds,
+                        ),
+                        amount,
+                    )?;
+
+                    Ok(false)
+                } else {
+                    self.accrue_debt(amount)?;
+
+                    Ok(true)
+                }
+            }
+            PayoutMode::Vested { cliff, duration } => {
+                self.accrue_vesting(amount, cliff, duration)?;
+
+                Ok(false)
+            }
+        }
+    }
+
+    /// Records a reward the treasury could not cover on the reward debt account of the wallet.
+    fn accrue_debt(&self, amount: u64) -> Result<()> {
+        let reward_center = self.reward_center.key();
+        let (reward_debt_account, reward_debt_bump) = self
+            .reward_debt
+            .as_ref()
+            .zip(self.reward_debt_bump)
+            .ok_or(RewardCenterError::RewardDebtAccountMissing)?;
+
+        if reward_debt_account.data_is_empty() {
+            create_pda_account(
+                reward_debt_account,
+                RewardDebt::size(),
+                &self.payer,
+                &self.system_program,
+                &[
+                    REWARD_DEBT.as_bytes(),
+                    reward_center.as_ref(),
+                    self.wallet.as_ref(),
+                    &[reward_debt_bump],
+                ],
+            )?;
+
+            RewardDebt {
+                reward_center,
+                wallet: self.wallet,
+                amount: 0,
+                bump: reward_debt_bump,
+            }
+            .try_serialize(&mut &mut reward_debt_account.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut reward_debt =
+            RewardDebt::try_deserialize(&mut &reward_debt_account.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            reward_debt.reward_center,
+            reward_center,
+            RewardCenterError::RewardDebtMismatch
+        );
+        require_keys_eq!(
+            reward_debt.wallet,
+            self.wallet,
+            RewardCenterError::RewardDebtMismatch
+        );
+
+        reward_debt.amount = reward_debt
+            .amount
+            .checked_add(amount)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        reward_debt.try_serialize(&mut &mut reward_debt_account.try_borrow_mut_data()?[..])?;
+
+        msg!(
+            "Reward treasury is short, recording {} as reward debt",
+            amount
+        );
+
+        emit!(RewardShortfall {
+            reward_center,
+            wallet: self.wallet,
+            shortfall_amount: amount,
+            total_debt: reward_debt.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Credits the reward to the reward vesting account of the wallet.
+    fn accrue_vesting(&self, amount: u64, cliff: i64, duration: i64) -> Result<()> {
+        let reward_center = self.reward_center.key();
+        let (reward_vesting_account, reward_vesting_bump) = self
+            .reward_vesting
+            .as_ref()
+            .zip(self.reward_vesting_bump)
+            .ok_or(RewardCenterError::RewardVestingAccountMissing)?;
+
+        if reward_vesting_account.data_is_empty() {
+            create_pda_account(
+                reward_vesting_account,
+                RewardVesting::size(),
+                &self.payer,
+                &self.system_program,
+                &[
+                    REWARD_VESTING.as_bytes(),
+                    reward_center.as_ref(),
+                    self.wallet.as_ref(),
+                    &[reward_vesting_bump],
+                ],
+            )?;
+
+            RewardVesting {
+                reward_center,
+                wallet: self.wallet,
+                carried_amount: 0,
+                schedule_amount: 0,
+                schedule_claimed_amount: 0,
+                start_at: 0,
+                cliff: 0,
+                duration: 0,
+                bump: reward_vesting_bump,
+            }
+            .try_serialize(&mut &mut reward_vesting_account.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut reward_vesting =
+            RewardVesting::try_deserialize(&mut &reward_vesting_account.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            reward_vesting.reward_center,
+            reward_center,
+            RewardCenterError::RewardVestingMismatch
+        );
+        require_keys_eq!(
+            reward_vesting.wallet,
+            self.wallet,
+            RewardCenterError::RewardVestingMismatch
+        );
+
+        reward_vesting.credit(amount, Clock::get()?.unix_timestamp, cliff, duration)?;
+
+        reward_vesting
+            .try_serialize(&mut &mut reward_vesting_account.try_borrow_mut_data()?[..])?;
+
+        msg!("Crediting {} to reward vesting", amount);
+
+        Ok(())
+    }
+}
+
+/// The part of a payout left owed to the wallet, as vesting or reward debt, that the treasury still
+/// has to cover.
+pub fn owed_amount(reward_center: &RewardCenter, payout: u64, recorded_as_debt: bool) -> u64 {
+    match reward_center.reward_rules.payout_mode {
+        PayoutMode::Immediate if !recorded_as_debt => 0,
+        _ => payout,
+    }
+}
+
+/// Transfers the attributor share of the reward pool. Attributor rewards are neither vested nor
+/// recorded as reward debt, so they are skipped when the treasury cannot cover them. Returns
+/// whether the transfer was skipped.
+pub fn pay_attributor<'info>(
+    reward_center: &Account<'info, RewardCenter>,
+    reward_center_reward_token_account: &mut Account<'info, TokenAccount>,
+    attributor_reward_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    reward_center_signer_seeds: &[&[&[u8]]],
+) -> Result<bool> {
+    if amount == 0 {
+        return Ok(false);
+    }
+
+    reward_center_reward_token_account.reload()?;
+
+    if reward_center_reward_token_account.amount < amount {
+        msg!(
+            "Reward treasury is short, skipping attributor reward of {}",
+            amount
+        );
+
+        return Ok(true);
+    }
+
+    transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                authority: reward_center.to_account_info(),
+                from: reward_center_reward_token_account.to_account_info(),
+                to: attributor_reward_token_account,
+            },
+            reward_center_signer_seeds,
+        ),
+        amount,
+    )?;
+
+    Ok(false)
+}
+
+/// Reports the payouts of a sale when the reward center is in shadow mode or evaluates candidate
+/// reward rules. Returns the seller, buyer and attributor payouts to pay, which are all zero in
+/// shadow mode. The reported payouts leave out the wallet caps, which are applied afterwards to
+/// the payouts actually paid.
+pub fn evaluate_rewards(
+    reward_center: &RewardCenter,
+    sale_executed: &SaleExecuted,
+    sale_path: SalePath,
+    attributed: bool,
+    (seller_payout, buyer_payout, attributor_payout): (u64, u64, u64),
+) -> Result<(u64, u64, u64)> {
+    let candidate_payouts =
+        reward_center.candidate_payouts(sale_executed.price, sale_path, attributed)?;
+
+    if reward_center.reward_mode == RewardMode::Live && candidate_payouts.is_none() {
+        return Ok((seller_payout, buyer_payout, attributor_payout));
+    }
+
+    emit!(RewardsEvaluated {
+        reward_center: sale_executed.reward_center,
+        seller: sale_executed.seller,
+        buyer: sale_executed.buyer,
+        metadata: sale_executed.metadata,
+        price: sale_executed.price,
+        reward_mode: reward_center.reward_mode,
+        payouts: RewardPayouts {
+            seller_payout,
+            buyer_payout,
+            attributor_payout,
+        },
+        candidate_payouts: candidate_payouts.map(
+            |(seller_payout, buyer_payout, attributor_payout)| RewardPayouts {
+                seller_payout,
+                buyer_payout,
+                attributor_payout,
+            },
+        ),
+    });
+
+    match reward_center.reward_mode {
+        RewardMode::Live => Ok((seller_payout, buyer_payout, attributor_payout)),
+        RewardMode::Shadow => {
+            msg!("Shadow mode, reporting rewards without paying them");
+
+            Ok((0, 0, 0))
+        }
+    }
+}
+
+/// Accounts of the buyer or the seller of a sale used to pay their rewards.
+pub struct SaleWallet<'info> {
+    pub wallet: Pubkey,
+    pub reward_token_account: AccountInfo<'info>,
+    pub reward_debt: Option<AccountInfo<'info>>,
+    pub reward_debt_bump: Option<u8>,
+    pub reward_vesting: Option<AccountInfo<'info>>,
+    pub reward_vesting_bump: Option<u8>,
+    pub reward_window: Option<AccountInfo<'info>>,
+    pub reward_window_bump: Option<u8>,
+}
+
+/// Accounts used to settle the rewards of an executed sale and record its purchase ticket.
+pub struct SaleSettlement<'a, 'info> {
+    pub reward_center: &'a mut Account<'info, RewardCenter>,
+    pub reward_center_reward_token_account: &'a mut Account<'info, TokenAccount>,
+    pub buyer: SaleWallet<'info>,
+    pub seller: SaleWallet<'info>,
+    /// mint of the NFT sold
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    /// listing address of the seller for the NFT, whether or not the sale went through it
+    pub listing: Pubkey,
+    /// offer address of the buyer for the NFT, whether or not the sale went through it
+    pub offer: Pubkey,
+    pub sale_record: Option<AccountInfo<'info>>,
+    pub sale_record_bump: Option<u8>,
+    pub purchase_ticket: AccountInfo<'info>,
+    pub purchase_ticket_bump: u8,
+    pub sale_counter: AccountInfo<'info>,
+    pub sale_counter_bump: u8,
+    pub attributor: Option<&'a Account<'info, Attributor>>,
+    pub attributor_reward_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub sponsor_pool: Option<&'a Account<'info, SponsorPool>>,
+    pub sponsor_pool_reward_token_account: Option<&'a mut Account<'info, TokenAccount>>,
+    pub collection_oracle: Option<&'a AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Terms of an executed sale.
+pub struct SaleTerms<'a> {
+    pub price: u64,
+    pub token_size: u64,
+    pub auction_house_fee: u64,
+    pub royalty_fee: u64,
+    /// metadata of the NFT sold
+    pub metadata: &'a Metadata,
+    /// whether the collection of the NFT is registered with the reward center
+    pub is_rewardable: bool,
+    /// whether the price of the resting order was set too recently to earn rewards
+    pub is_order_too_young: bool,
+}
+
+/// Settles an executed sale. Rewards are withheld when the sale does not qualify, otherwise the
+/// buyer, seller and attributor are paid from the reward treasury. Sponsor pool rewards are paid
+/// on top, then the purchase ticket is recorded and the order is closed out on the reward center.
+/// The payer funds the accounts the sale creates.
+pub fn settle_sale<'info>(
+    SaleSettlement {
+        reward_center,
+        reward_center_reward_token_account,
+        buyer,
+        seller,
+        mint,
+        metadata,
+        listing,
+        offer,
+        sale_record,
+        sale_record_bump,
+        purchase_ticket,
+        purchase_ticket_bump,
+        sale_counter,
+        sale_counter_bump,
+        attributor,
+        attributor_reward_token_account,
+        sponsor_pool,
+        sponsor_pool_reward_token_account,
+        collection_oracle,
+        token_program,
+        system_program,
+    }: SaleSettlement<'_, 'info>,
+    SaleTerms {
+        price,
+        token_size,
+        auction_house_fee,
+        royalty_fee,
+        metadata: md,
+        is_rewardable,
+        is_order_too_young,
+    }: SaleTerms,
+    sale_path: SalePath,
+    payer: AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let auction_house = reward_center.auction_house;
+    let reward_center_bump = reward_center.bump;
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house.as_ref(),
+        &[reward_center_bump],
+    ]];
+
+    let rewards_paused = reward_center.is_paused(PauseFlags::REWARDS);
+    let oracle_approved = reward_center.is_approved_by_oracle(collection_oracle);
+
+    let attributor = match (attributor, attributor_reward_token_account) {
+        (Some(attributor), Some(attributor_reward_token_account)) => {
+            require_keys_eq!(
+                attributor.reward_center,
+                reward_center.key(),
+                RewardCenterError::AttributorMismatch
+            );
+            require_keys_eq!(
+                attributor_reward_token_account.owner,
+                attributor.wallet,
+                RewardCenterError::TokenOwnerMismatch
+            );
+            require_keys_eq!(
+                attributor_reward_token_account.mint,
+                reward_center.token_mint,
+                RewardCenterError::MintMismatch
+            );
+
+            Some((attributor, attributor_reward_token_account))
+        }
+        (None, None) => None,
+        _ => return err!(RewardCenterError::AttributorMismatch),
+    };
+
+    let sponsor_pool = match (sponsor_pool, sponsor_pool_reward_token_account) {
+        (Some(sponsor_pool), Some(sponsor_pool_reward_token_account)) => {
+            require_keys_eq!(
+                sponsor_pool.reward_center,
+                reward_center.key(),
+                RewardCenterError::SponsorPoolMismatch
+            );
+            require!(
+                sponsor_pool.sponsors(md),
+                RewardCenterError::SponsorPoolMismatch
+            );
+            require_keys_eq!(
+                sponsor_pool_reward_token_account.owner,
+                sponsor_pool.key(),
+                RewardCenterError::TokenOwnerMismatch
+            );
+            require_keys_eq!(
+                sponsor_pool_reward_token_account.mint,
+                reward_center.token_mint,
+                RewardCenterError::MintMismatch
+            );
+
+            Some((sponsor_pool, sponsor_pool_reward_token_account))
+        }
+        (None, None) => None,
+        _ => return err!(RewardCenterError::SponsorPoolMismatch),
+    };
+
+    let mut attributor_payout = 0;
+    let mut attributor_payout_skipped = false;
+    let mut owed_rewards = 0;
+
+    let mut sale_executed = SaleExecuted {
+        reward_center: reward_center.key(),
+        seller: seller.wallet,
+        buyer: buyer.wallet,
+        metadata,
+        price,
+        token_size,
+        auction_house_fee,
+        royalty_fee,
+        seller_payout: 0,
+        buyer_payout: 0,
+        seller_payout_skipped: false,
+        buyer_payout_skipped: false,
+    };
+
+    let withheld_reason = |is_registered: bool| {
+        if rewards_paused {
+            Some(RewardsWithheldReason::RewardsPaused)
+        } else if !is_registered {
+            Some(RewardsWithheldReason::CollectionNotRewardable)
+        } else if !oracle_approved {
+            Some(RewardsWithheldReason::CollectionOracleNotApproved)
+        } else if is_order_too_young {
+            Some(RewardsWithheldReason::OrderTooYoung)
+        } else {
+            None
+        }
+    };
+
+    let rewards_withheld_reason = withheld_reason(is_rewardable);
+
+    // Sponsor pools pay collections outside the registry of the reward center, but are withheld
+    // for every other reason its own rewards are
+    let sponsor_pool = sponsor_pool.filter(|_| withheld_reason(true).is_none());
+
+    let is_wash_trade = SaleRecordUpdate {
+        sale_record,
+        sale_record_bump,
+        reward_center: reward_center.key(),
+        mint,
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+    }
+    .record(
+        buyer.wallet,
+        seller.wallet,
+        now,
+        reward_center.reward_rules.wash_trade_window_seconds,
+        rewards_withheld_reason.is_none() || sponsor_pool.is_some(),
+    )?;
+
+    let rewards_withheld_reason = match rewards_withheld_reason {
+        None if is_wash_trade => Some(RewardsWithheldReason::WashTrade),
+        reason => reason,
+    };
+
+    if let Some(reason) = rewards_withheld_reason {
+        msg!("Skipping rewards: {:?}", reason);
+
+        emit!(RewardsWithheld {
+            reward_center: sale_executed.reward_center,
+            seller: sale_executed.seller,
+            buyer: sale_executed.buyer,
+            metadata: sale_executed.metadata,
+            reason,
+        });
+    } else {
+        let (seller_payout, buyer_payout, attributor_share) = evaluate_rewards(
+            reward_center,
+            &sale_executed,
+            sale_path,
+            attributor.is_some(),
+            reward_center.payouts(price, sale_path, attributor.is_some())?,
+        )?;
+
+        let buyer_payout = WalletRewardWindowUpdate {
+            wallet_reward_window: buyer.reward_window.clone(),
+            wallet_reward_window_bump: buyer.reward_window_bump,
+            reward_center: reward_center.key(),
+            wallet: buyer.wallet,
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+        }
+        .clamp(
+            buyer_payout,
+            reward_center.reward_rules.max_reward_per_wallet_per_epoch,
+        )?;
+
+        let seller_payout = WalletRewardWindowUpdate {
+            wallet_reward_window: seller.reward_window.clone(),
+            wallet_reward_window_bump: seller.reward_window_bump,
+            reward_center: reward_center.key(),
+            wallet: seller.wallet,
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+        }
+        .clamp(
+            seller_payout,
+            reward_center.reward_rules.max_reward_per_wallet_per_epoch,
+        )?;
+
+        sale_executed.seller_payout = seller_payout;
+        sale_executed.buyer_payout = buyer_payout;
+
+        // Buyer payout
+        sale_executed.buyer_payout_skipped = RewardPayout {
+            reward_center,
+            reward_center_reward_token_account,
+            wallet: buyer.wallet,
+            reward_token_account: buyer.reward_token_account.clone(),
+            reward_debt: buyer.reward_debt,
+            reward_debt_bump: buyer.reward_debt_bump,
+            reward_vesting: buyer.reward_vesting,
+            reward_vesting_bump: buyer.reward_vesting_bump,
+            payer: payer.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+        }
+        .pay(buyer_payout, reward_center_signer_seeds)?;
+
+        // Seller payout
+        sale_executed.seller_payout_skipped = RewardPayout {
+            reward_center,
+            reward_center_reward_token_account,
+            wallet: seller.wallet,
+            reward_token_account: seller.reward_token_account.clone(),
+            reward_debt: seller.reward_debt,
+            reward_debt_bump: seller.reward_debt_bump,
+            reward_vesting: seller.reward_vesting,
+            reward_vesting_bump: seller.reward_vesting_bump,
+            payer: payer.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+        }
+        .pay(seller_payout, reward_center_signer_seeds)?;
+
+        owed_rewards = owed_amount(
+            reward_center,
+            buyer_payout,
+            sale_executed.buyer_payout_skipped,
+        )
+        .checked_add(owed_amount(
+            reward_center,
+            seller_payout,
+            sale_executed.seller_payout_skipped,
+        ))
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        // Attributor payout
+        if let Some((_, attributor_reward_token_account)) = &attributor {
+            attributor_payout = attributor_share;
+            attributor_payout_skipped = pay_attributor(
+                reward_center,
+                reward_center_reward_token_account,
+                attributor_reward_token_account.to_account_info(),
+                token_program.clone(),
+                attributor_payout,
+                reward_center_signer_seeds,
+            )?;
+        }
+    }
+
+    // Sponsor pool payouts
+    if let Some((sponsor_pool, sponsor_pool_reward_token_account)) =
+        sponsor_pool.filter(|_| !is_wash_trade)
+    {
+        SponsorPoolPayout {
+            reward_center,
+            sponsor_pool,
+            sponsor_pool_reward_token_account,
+            seller_reward_token_account: seller.reward_token_account,
+            buyer_reward_token_account: buyer.reward_token_account,
+            seller_reward_window: WalletRewardWindowUpdate {
+                wallet_reward_window: seller.reward_window,
+                wallet_reward_window_bump: seller.reward_window_bump,
+                reward_center: reward_center.key(),
+                wallet: seller.wallet,
+                payer: payer.clone(),
+                system_program: system_program.clone(),
+            },
+            buyer_reward_window: WalletRewardWindowUpdate {
+                wallet_reward_window: buyer.reward_window,
+                wallet_reward_window_bump: buyer.reward_window_bump,
+                reward_center: reward_center.key(),
+                wallet: buyer.wallet,
+                payer: payer.clone(),
+                system_program: system_program.clone(),
+            },
+            token_program,
+        }
+        .pay(&sale_executed, sale_path)?;
+    }
+
+    PurchaseTicketRecord {
+        purchase_ticket,
+        purchase_ticket_bump,
+        sale_counter,
+        sale_counter_bump,
+        reward_center: sale_executed.reward_center,
+        mint,
+        listing,
+        offer,
+        payer: payer.clone(),
+        system_program,
+    }
+    .record(PurchaseTicket {
+        reward_center: sale_executed.reward_center,
+        buyer: sale_executed.buyer,
+        seller: sale_executed.seller,
+        metadata: sale_executed.metadata,
+        price,
+        token_size,
+        buyer_reward: sale_executed.buyer_payout,
+        seller_reward: sale_executed.seller_payout,
+        payer: payer.key(),
+        created_at: now,
+        sale_number: 0,
+        bump: 0,
+    })?;
+
+    if let Some((attributor, _)) = &attributor {
+        emit!(SaleAttributed {
+            reward_center: sale_executed.reward_center,
+            attributor: attributor.key(),
+            attributor_wallet: attributor.wallet,
+            seller: sale_executed.seller,
+            buyer: sale_executed.buyer,
+            metadata: sale_executed.metadata,
+            price,
+            attributor_payout,
+            attributor_payout_skipped,
+        });
+    }
+
+    emit!(sale_executed);
+
+    match sale_path {
+        SalePath::BuyListing => {
+            reward_center.open_listings = reward_center.open_listings.saturating_sub(1);
+        }
+        SalePath::AcceptOffer => {
+            reward_center.open_offers = reward_center.open_offers.saturating_sub(1);
+        }
+    }
+
+    reward_center.outstanding_rewards = reward_center
+        .outstanding_rewards
+        .checked_add(owed_rewards)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    Ok(())
+}
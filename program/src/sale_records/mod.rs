@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SALE_RECORD, errors::RewardCenterError, state::SaleRecord, utils::create_pda_account,
+};
+
+/// Accounts used to record the last sale of an NFT.
+pub struct SaleRecordUpdate<'info> {
+    /// only needed while the wash trade window is set
+    pub sale_record: Option<AccountInfo<'info>>,
+    pub sale_record_bump: Option<u8>,
+    pub reward_center: Pubkey,
+    /// mint of the NFT sold
+    pub mint: Pubkey,
+    /// signer funding the sale record when it has to be created
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> SaleRecordUpdate<'info> {
+    /// Records the sale on the sale record of the NFT. Returns whether the sale looks like a wash
+    /// trade against the previous sale of the NFT.
+    ///
+    /// Self trades are flagged without a sale record. Nothing is recorded while the wash trade
+    /// window is 0, and the sale record is only created when the sale has rewards at stake, so
+    /// sales earning nothing do not pay for it.
+    pub fn record(
+        self,
+        buyer: Pubkey,
+        seller: Pubkey,
+        now: i64,
+        wash_trade_window_seconds: i64,
+        rewards_at_stake: bool,
+    ) -> Result<bool> {
+        if buyer == seller {
+            return Ok(true);
+        }
+
+        if wash_trade_window_seconds == 0 {
+            return Ok(false);
+        }
+
+        let (sale_record_account, sale_record_bump) = self
+            .sale_record
+            .as_ref()
+            .zip(self.sale_record_bump)
+            .ok_or(RewardCenterError::SaleRecordAccountMissing)?;
+
+        if sale_record_account.data_is_empty() {
+            if !rewards_at_stake {
+                return Ok(false);
+            }
+
+            create_pda_account(
+                sale_record_account,
+                SaleRecord::size(),
+                &self.payer,
+                &self.system_program,
+                &[
+                    SALE_RECORD.as_bytes(),
+                    self.reward_center.as_ref(),
+                    self.mint.as_ref(),
+                    &[sale_record_bump],
+                ],
+            )?;
+
+            SaleRecord {
+                reward_center: self.reward_center,
+                mint: self.mint,
+                last_buyer: Pubkey::default(),
+                last_seller: Pubkey::default(),
+                last_sale_at: 0,
+                bump: sale_record_bump,
+            }
+            .try_serialize(&mut &mut sale_record_account.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut sale_record =
+            SaleRecord::try_deserialize(&mut &sale_record_account.try_borrow_data()?[..])?;
+
+        let is_wash_trade =
+            sale_record.is_wash_trade(&buyer, &seller, now, wash_trade_window_seconds);
+
+        sale_record.last_buyer = buyer;
+        sale_record.last_seller = seller;
+        sale_record.last_sale_at = now;
+
+        sale_record.try_serialize(&mut &mut sale_record_account.try_borrow_mut_data()?[..])?;
+
+        Ok(is_wash_trade)
+    }
+}
@@ -0,0 +1,125 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use mtly_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{REWARD_CENTER, SPONSOR_POOL},
+    errors::RewardCenterError,
+    state::{RewardCenter, SponsorPool, SponsorRules},
+};
+
+/// Options to set on the sponsor pool
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateSponsorPoolParams {
+    pub sponsor_rules: SponsorRules,
+}
+
+/// Accounts for the [`create_sponsor_pool` handler](reward_center/fn.create_sponsor_pool.html).
+#[derive(Accounts, Clone)]
+#[instruction(create_sponsor_pool_params: CreateSponsorPoolParams)]
+pub struct CreateSponsorPool<'info> {
+    /// The update authority of the collection, becoming the sponsor of the pool.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The mint of the Metaplex collection NFT to sponsor.
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Owner checked in constraint, mint and update authority checked in the handler.
+    /// The metadata of the collection NFT.
+    #[account(owner = mpl_token_metadata::ID)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// The mint of the reward token of the reward center.
+    #[account(constraint = reward_mint.key() == reward_center.token_mint @ RewardCenterError::MintMismatch)]
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    /// The sponsor pool of the collection.
+    #[account(
+        init,
+        payer = wallet,
+        space = SponsorPool::size(),
+        seeds = [
+            SPONSOR_POOL.as_bytes(),
+            reward_center.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub sponsor_pool: Box<Account<'info, SponsorPool>>,
+
+    /// The token account holding the reward tokens of the sponsor pool.
+    #[account(
+        init,
+        payer = wallet,
+        associated_token::mint = reward_mint,
+        associated_token::authority = sponsor_pool
+    )]
+    pub sponsor_pool_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mtly_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSponsorPool>,
+    CreateSponsorPoolParams { sponsor_rules }: CreateSponsorPoolParams,
+) -> Result<()> {
+    sponsor_rules.validate()?;
+
+    let collection_metadata = Metadata::from_account_info(&ctx.accounts.collection_metadata)?;
+
+    require_keys_eq!(
+        collection_metadata.mint,
+        ctx.accounts.collection_mint.key(),
+        RewardCenterError::MintMismatch
+    );
+    require_keys_eq!(
+        collection_metadata.update_authority,
+        ctx.accounts.wallet.key(),
+        RewardCenterError::SignerNotAuthorized
+    );
+
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+
+    sponsor_pool.reward_center = ctx.accounts.reward_center.key();
+    sponsor_pool.collection = ctx.accounts.collection_mint.key();
+    sponsor_pool.authority = ctx.accounts.wallet.key();
+    sponsor_pool.bump = *ctx
+        .bumps
+        .get("sponsor_pool")
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    sponsor_pool.sponsor_rules = sponsor_rules;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::SPONSOR_POOL, errors::RewardCenterError, events::SponsorPoolFunded,
+    state::SponsorPool,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DepositSponsorPoolFundsParams {
+    pub deposit_amount: u64,
+}
+
+/// Accounts for the [`deposit_sponsor_pool_funds` handler](reward_center/fn.deposit_sponsor_pool_funds.html).
+#[derive(Accounts, Clone)]
+#[instruction(deposit_sponsor_pool_funds_params: DepositSponsorPoolFundsParams)]
+pub struct DepositSponsorPoolFunds<'info> {
+    /// The sponsor of the pool.
+    #[
+      account(
+        constraint = wallet.key() == sponsor_pool.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    // Reward token account of the sponsor the rewards get transferred from
+    #[account(
+        mut,
+        constraint = source_reward_token_account.mint == sponsor_pool_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = source_reward_token_account.owner == wallet.key() @ RewardCenterError::TokenOwnerMismatch,
+        constraint = source_reward_token_account.amount >= deposit_sponsor_pool_funds_params.deposit_amount @ RewardCenterError::InsufficientFunds,
+    )]
+    pub source_reward_token_account: Account<'info, TokenAccount>,
+
+    // Sponsor pool reward token account
+    #[account(
+        mut,
+        constraint = sponsor_pool_reward_token_account.owner == sponsor_pool.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub sponsor_pool_reward_token_account: Account<'info, TokenAccount>,
+
+    /// The sponsor pool of the collection.
+    #[account(
+        seeds = [
+            SPONSOR_POOL.as_bytes(),
+            sponsor_pool.reward_center.as_ref(),
+            sponsor_pool.collection.as_ref()
+        ],
+        bump = sponsor_pool.bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<DepositSponsorPoolFunds>,
+    DepositSponsorPoolFundsParams { deposit_amount }: DepositSponsorPoolFundsParams,
+) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_reward_token_account.to_account_info(),
+                to: ctx
+                    .accounts
+                    .sponsor_pool_reward_token_account
+                    .to_account_info(),
+                authority: ctx.accounts.wallet.to_account_info(),
+            },
+        ),
+        deposit_amount,
+    )?;
+
+    emit!(SponsorPoolFunded {
+        sponsor_pool: ctx.accounts.sponsor_pool.key(),
+        sponsor: ctx.accounts.wallet.key(),
+        amount: deposit_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+pub mod create;
+pub mod deposit;
+pub mod withdraw;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, TokenAccount, Transfer};
+
+use crate::{
+    constants::SPONSOR_POOL,
+    errors::RewardCenterError,
+    events::{SaleExecuted, SponsorRewardsPaid},
+    state::{RewardCenter, RewardMode, SalePath, SponsorPool},
+    wallet_reward_windows::WalletRewardWindowUpdate,
+};
+
+/// Accounts used to pay the sponsor pool rewards of a sale to the buyer and the seller.
+pub struct SponsorPoolPayout<'a, 'info> {
+    pub reward_center: &'a RewardCenter,
+    pub sponsor_pool: &'a Account<'info, SponsorPool>,
+    pub sponsor_pool_reward_token_account: &'a mut Account<'info, TokenAccount>,
+    pub seller_reward_token_account: AccountInfo<'info>,
+    pub buyer_reward_token_account: AccountInfo<'info>,
+    /// reward windows holding the payouts under the per wallet cap of the reward center
+    pub seller_reward_window: WalletRewardWindowUpdate<'info>,
+    pub buyer_reward_window: WalletRewardWindowUpdate<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'a, 'info> SponsorPoolPayout<'a, 'info> {
+    /// Transfers the rewards of the sale under the sponsor rules of the sponsor pool, counting
+    /// them against the per wallet cap of the reward center along with its own rewards. Sponsor
+    /// rewards are neither vested nor recorded as reward debt, so they are skipped when the pool
+    /// cannot cover them or the reward center is in shadow mode.
+    pub fn pay(self, sale_executed: &SaleExecuted, sale_path: SalePath) -> Result<()> {
+        let (mut seller_payout, mut buyer_payout, _) = self.reward_center.payouts_under(
+            &self.sponsor_pool.sponsor_rules.reward_rules(),
+            sale_executed.price,
+            sale_path,
+            false,
+        )?;
+
+        let total_payout = seller_payout
+            .checked_add(buyer_payout)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        self.sponsor_pool_reward_token_account.reload()?;
+
+        let skipped = if self.reward_center.reward_mode == RewardMode::Shadow {
+            true
+        } else if self.sponsor_pool_reward_token_account.amount < total_payout {
+            msg!(
+                "Sponsor pool is short, skipping sponsor rewards of {} and {}",
+                seller_payout,
+                buyer_payout
+            );
+
+            true
+        } else {
+            let max_reward_per_wallet_per_epoch = self
+                .reward_center
+                .reward_rules
+                .max_reward_per_wallet_per_epoch;
+
+            seller_payout = self
+                .seller_reward_window
+                .clamp(seller_payout, max_reward_per_wallet_per_epoch)?;
+            buyer_payout = self
+                .buyer_reward_window
+                .clamp(buyer_payout, max_reward_per_wallet_per_epoch)?;
+
+            self.transfer(self.seller_reward_token_account.clone(), seller_payout)?;
+            self.transfer(self.buyer_reward_token_account.clone(), buyer_payout)?;
+
+            false
+        };
+
+        emit!(SponsorRewardsPaid {
+            reward_center: sale_executed.reward_center,
+            sponsor_pool: self.sponsor_pool.key(),
+            seller: sale_executed.seller,
+            buyer: sale_executed.buyer,
+            metadata: sale_executed.metadata,
+            seller_payout,
+            buyer_payout,
+            skipped,
+        });
+
+        Ok(())
+    }
+
+    fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                Transfer {
+                    authority: self.sponsor_pool.to_account_info(),
+                    from: self.sponsor_pool_reward_token_account.to_account_info(),
+                    to,
+                },
+                &[&[
+                    SPONSOR_POOL.as_bytes(),
+                    self.sponsor_pool.reward_center.as_ref(),
+                    self.sponsor_pool.collection.as_ref(),
+                    &[self.sponsor_pool.bump],
+                ]],
+            ),
+            amount,
+        )
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+use solana_program::program_pack::IsInitialized;
+
+use crate::{
+    constants::SPONSOR_POOL, errors::RewardCenterError, events::SponsorPoolWithdrawn,
+    state::SponsorPool,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct WithdrawSponsorPoolFundsParams {
+    pub withdrawal_amount: u64,
+}
+
+/// Accounts for the [`withdraw_sponsor_pool_funds` handler](reward_center/fn.withdraw_sponsor_pool_funds.html).
+#[derive(Accounts, Clone)]
+#[instruction(withdraw_sponsor_pool_funds_params: WithdrawSponsorPoolFundsParams)]
+pub struct WithdrawSponsorPoolFunds<'info> {
+    /// The sponsor of the pool.
+    #[
+      account(
+        constraint = wallet.key() == sponsor_pool.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    // Sponsor pool reward token account
+    #[account(
+        mut,
+        constraint = sponsor_pool_reward_token_account.owner == sponsor_pool.key() @ RewardCenterError::TokenOwnerMismatch,
+        constraint = sponsor_pool_reward_token_account.amount >= withdraw_sponsor_pool_funds_params.withdrawal_amount @ RewardCenterError::InsufficientFunds,
+    )]
+    pub sponsor_pool_reward_token_account: Account<'info, TokenAccount>,
+
+    // Destination reward token account where the rewards get transferred
+    #[account(
+        mut,
+        constraint = destination_reward_token_account.mint == sponsor_pool_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = destination_reward_token_account.owner == wallet.key() @ RewardCenterError::TokenOwnerMismatch,
+        constraint = destination_reward_token_account.is_initialized() @
+        ProgramError::UninitializedAccount
+    )]
+    pub destination_reward_token_account: Account<'info, TokenAccount>,
+
+    /// The sponsor pool of the collection.
+    #[account(
+        seeds = [
+            SPONSOR_POOL.as_bytes(),
+            sponsor_pool.reward_center.as_ref(),
+            sponsor_pool.collection.as_ref()
+        ],
+        bump = sponsor_pool.bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawSponsorPoolFunds>,
+    WithdrawSponsorPoolFundsParams { withdrawal_amount }: WithdrawSponsorPoolFundsParams,
+) -> Result<()> {
+    let sponsor_pool = &ctx.accounts.sponsor_pool;
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx
+                    .accounts
+                    .sponsor_pool_reward_token_account
+                    .to_account_info(),
+                to: ctx
+                    .accounts
+                    .destination_reward_token_account
+                    .to_account_info(),
+                authority: sponsor_pool.to_account_info(),
+            },
+            &[&[
+                SPONSOR_POOL.as_bytes(),
+                sponsor_pool.reward_center.as_ref(),
+                sponsor_pool.collection.as_ref(),
+                &[sponsor_pool.bump],
+            ]],
+        ),
+        withdrawal_amount,
+    )?;
+
+    emit!(SponsorPoolWithdrawn {
+        sponsor_pool: sponsor_pool.key(),
+        sponsor: ctx.accounts.wallet.key(),
+        destination_reward_token_account: ctx.accounts.destination_reward_token_account.key(),
+        amount: withdrawal_amount,
+    });
+
+    Ok(())
+}
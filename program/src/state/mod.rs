@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use mpl_token_metadata::state::Metadata;
 
 use crate::errors::RewardCenterError;
 
@@ -8,6 +9,115 @@ pub enum PayoutOperation {
     Divide,
 }
 
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub enum PayoutMode {
+    // Rewards are transferred to the buyer and seller as part of the sale
+    Immediate,
+
+    // Rewards are credited to a vesting account and released linearly over duration seconds,
+    // with nothing claimable before cliff seconds have passed
+    Vested { cliff: i64, duration: i64 },
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub struct MakerTakerSplit {
+    // Basis Points of the reward pool for the side whose listing or offer was resting
+    pub maker_reward_basis_points: u16,
+
+    // Basis Points of the reward pool for the side that took the listing or offer
+    pub taker_reward_basis_points: u16,
+}
+
+/// Maximum number of tiers of a [`RewardCurve::Tiered`] curve
+pub const MAX_REWARD_TIERS: usize = 8;
+
+/// A tier of a [`RewardCurve::Tiered`] curve, rewarding the part of the price between its threshold
+/// and the next one at numerator / denominator
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub struct RewardTier {
+    pub price_threshold: u64,
+    pub numerator: u16,
+    pub denominator: u16,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub enum RewardCurve {
+    // Rewards follow the payout operation and numeral applied to the whole price
+    Linear,
+
+    // Each tier rewards the part of the price between its threshold and the next one.
+    // Thresholds are increasing and the part of the price below the first threshold earns nothing.
+    Tiered(Vec<RewardTier>),
+
+    // Linear rewards, limited to max_reward reward tokens per sale
+    Capped { max_reward: u64 },
+}
+
+impl RewardCurve {
+    /// Rejects tiers that are unbounded, out of order or divide by zero.
+    pub fn validate(&self) -> Result<()> {
+        if let RewardCurve::Tiered(tiers) = self {
+            require!(
+                !tiers.is_empty() && tiers.len() <= MAX_REWARD_TIERS,
+                RewardCenterError::InvalidRewardCurve
+            );
+            require!(
+                tiers
+                    .windows(2)
+                    .all(|pair| pair[0].price_threshold < pair[1].price_threshold),
+                RewardCenterError::InvalidRewardCurve
+            );
+            require!(
+                tiers.iter().all(|tier| tier.denominator > 0),
+                RewardCenterError::InvalidRewardCurve
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Sums the rewards of every tier the price reaches into, in treasury mint units.
+    pub fn tiered_payout(tiers: &[RewardTier], listing_price: u64) -> Result<u64> {
+        let mut total_payout: u128 = 0;
+
+        for (index, tier) in tiers.iter().enumerate() {
+            if listing_price <= tier.price_threshold {
+                break;
+            }
+
+            let tier_ceiling = tiers.get(index + 1).map_or(listing_price, |next_tier| {
+                listing_price.min(next_tier.price_threshold)
+            });
+
+            let tier_payout = ((tier_ceiling - tier.price_threshold) as u128)
+                .checked_mul(tier.numerator.into())
+                .and_then(|product| product.checked_div(tier.denominator.into()))
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+            total_payout = total_payout
+                .checked_add(tier_payout)
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+        }
+
+        u64::try_from(total_payout).map_err(|_| RewardCenterError::NumericalOverflowError.into())
+    }
+
+    pub fn size() -> usize {
+        1 + // variant
+        4 + MAX_REWARD_TIERS * (8 + 2 + 2) // largest variant, tiered
+    }
+}
+
+/// How a sale was brokered, which decides whether the seller or the buyer was the maker
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SalePath {
+    // The buyer takes a resting listing, so the seller is the maker
+    BuyListing,
+
+    // The seller takes a resting offer, so the buyer is the maker
+    AcceptOffer,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct RewardRules {
     // Basis Points to determine reward ratio for seller
@@ -18,29 +128,261 @@ pub struct RewardRules {
 
     // Payout numeral for determining reward distribution to seller/buyer
     pub payout_numeral: u16,
+
+    // Whether rewards are paid out immediately or vested over time
+    pub payout_mode: PayoutMode,
+
+    // Basis Points of the reward pool routed to the attributor of a sale
+    pub attributor_reward_basis_points: u16,
+
+    // Off-chain service that has to co-sign a sale for its rewards to be paid
+    pub collection_oracle: Option<Pubkey>,
+
+    // Maker and taker shares replacing the seller/buyer split when set
+    pub maker_taker_split: Option<MakerTakerSplit>,
+
+    // Shape of the reward pool as the price grows
+    pub reward_curve: RewardCurve,
+
+    // Seconds after a sale during which reversing it, or flipping the NFT again, earns no rewards
+    pub wash_trade_window_seconds: i64,
+
+    // Seconds a listing or offer has to rest before filling it earns rewards
+    pub min_order_age_seconds: i64,
+
+    // Most reward tokens a single sale pays out in total
+    pub max_reward_per_sale: Option<u64>,
+
+    // Most reward tokens a wallet earns as buyer or seller within one epoch
+    pub max_reward_per_wallet_per_epoch: Option<u64>,
+}
+
+impl RewardRules {
+    pub fn size() -> usize {
+        2 + 1 + 2 + // listing reward rules
+        1 + 8 + 8 + // payout mode
+        2 + // attributor reward basis points
+        1 + 32 + // optional collection oracle
+        1 + 2 + 2 + // optional maker taker split
+        RewardCurve::size() + // reward curve
+        8 + // wash trade window seconds
+        8 + // min order age seconds
+        1 + 8 + // optional max reward per sale
+        1 + 8 // optional max reward per wallet per epoch
+    }
+
+    /// Rejects rules that would make every rewarded sale fail.
+    pub fn validate(&self) -> Result<()> {
+        require_gte!(
+            10000,
+            self.seller_reward_payout_basis_points,
+            RewardCenterError::InvalidSellerRewardPayoutBasisPoints
+        );
+
+        if let PayoutOperation::Divide = self.mathematical_operand {
+            require_gt!(
+                self.payout_numeral,
+                0,
+                RewardCenterError::InvalidPayoutNumeral
+            );
+        }
+
+        require_gte!(
+            10000,
+            self.attributor_reward_basis_points,
+            RewardCenterError::InvalidAttributorRewardBasisPoints
+        );
+
+        if let Some(MakerTakerSplit {
+            maker_reward_basis_points,
+            taker_reward_basis_points,
+        }) = self.maker_taker_split
+        {
+            require_gte!(
+                10000,
+                u32::from(maker_reward_basis_points) + u32::from(taker_reward_basis_points),
+                RewardCenterError::InvalidMakerTakerBasisPoints
+            );
+        }
+
+        self.reward_curve.validate()?;
+
+        require_gte!(
+            self.wash_trade_window_seconds,
+            0,
+            RewardCenterError::InvalidWashTradeWindow
+        );
+
+        require_gte!(
+            self.min_order_age_seconds,
+            0,
+            RewardCenterError::InvalidMinOrderAge
+        );
+
+        if let PayoutMode::Vested { cliff, duration } = self.payout_mode {
+            require!(
+                cliff >= 0 && duration >= 0 && cliff <= duration,
+                RewardCenterError::InvalidVestingSchedule
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Reward rules of a sponsor pool, limited to the rules sizing and splitting the rewards of a
+/// sale. The other checks of a sale are those of the reward center.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct SponsorRules {
+    // Basis Points to determine reward ratio for seller
+    pub seller_reward_payout_basis_points: u16,
+
+    // Payout operation to consider when taking payout_numeral into account
+    pub mathematical_operand: PayoutOperation,
+
+    // Payout numeral for determining reward distribution to seller/buyer
+    pub payout_numeral: u16,
+
+    // Maker and taker shares replacing the seller/buyer split when set
+    pub maker_taker_split: Option<MakerTakerSplit>,
+
+    // Shape of the reward pool as the price grows
+    pub reward_curve: RewardCurve,
+
+    // Most reward tokens a single sale pays out of the pool in total
+    pub max_reward_per_sale: Option<u64>,
+}
+
+impl SponsorRules {
+    pub fn size() -> usize {
+        2 + 1 + 2 + // listing reward rules
+        1 + 2 + 2 + // optional maker taker split
+        RewardCurve::size() + // reward curve
+        1 + 8 // optional max reward per sale
+    }
+
+    /// Rejects rules that would make every sponsored sale fail.
+    pub fn validate(&self) -> Result<()> {
+        self.reward_rules().validate()
+    }
+
+    /// The sponsor rules as reward rules, paying immediately without attribution.
+    pub fn reward_rules(&self) -> RewardRules {
+        RewardRules {
+            seller_reward_payout_basis_points: self.seller_reward_payout_basis_points,
+            mathematical_operand: self.mathematical_operand.clone(),
+            payout_numeral: self.payout_numeral,
+            payout_mode: PayoutMode::Immediate,
+            attributor_reward_basis_points: 0,
+            collection_oracle: None,
+            maker_taker_split: self.maker_taker_split.clone(),
+            reward_curve: self.reward_curve.clone(),
+            wash_trade_window_seconds: 0,
+            min_order_age_seconds: 0,
+            max_reward_per_sale: self.max_reward_per_sale,
+            max_reward_per_wallet_per_epoch: None,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq)]
+pub enum RewardMode {
+    // Rewards are transferred, vested or recorded as reward debt
+    Live,
+
+    // Rewards are computed and reported in an event without being paid
+    Shadow,
+}
+
+/// Bitflags for the reward center operations that can be paused independently
+pub struct PauseFlags;
+
+impl PauseFlags {
+    pub const LISTINGS: u8 = 1 << 0;
+    pub const OFFERS: u8 = 1 << 1;
+    pub const SALES: u8 = 1 << 2;
+    pub const REWARDS: u8 = 1 << 3;
+    pub const ALL: u8 = Self::LISTINGS | Self::OFFERS | Self::SALES | Self::REWARDS;
 }
 
 #[account]
 #[derive(Debug)]
 pub struct RewardCenter {
+    /// the layout version of the account, upgraded by migrate_reward_center
+    pub version: u8,
     /// the mint of the token used as rewards
     pub token_mint: Pubkey,
     /// the auction house associated to the reward center
     pub auction_house: Pubkey,
-    /// rules for listing rewards
-    pub reward_rules: RewardRules,
+    /// the wallet allowed to manage the reward center
+    pub authority: Pubkey,
+    /// the wallet proposed to take over as authority
+    pub pending_authority: Option<Pubkey>,
+    /// the decimals of the auction house treasury mint
+    pub treasury_mint_decimals: u8,
+    /// the decimals of the reward token mint
+    pub reward_mint_decimals: u8,
+    /// the paused operations as a set of [`PauseFlags`]
+    pub pause_state: u8,
+    /// the number of open listings, counted so the reward center is not closed under them
+    pub open_listings: u64,
+    /// the number of open offers, counted so the reward center is not closed under them
+    pub open_offers: u64,
     /// the bump of the pda
     pub bump: u8,
+    /// rules for listing rewards
+    pub reward_rules: RewardRules,
+    /// rules evaluated next to the reward rules on every rewarded sale without paying out
+    pub candidate_reward_rules: Option<RewardRules>,
+    /// whether sales pay out rewards or only report them
+    pub reward_mode: RewardMode,
+    /// the rewards owed to wallets as reward debt or vesting, still to be paid by the treasury
+    pub outstanding_rewards: u64,
+    /// whether the reward center was upgraded from a version that did not count all of its open
+    /// orders and outstanding rewards
+    pub has_uncounted_obligations: bool,
+    /// zeroed space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl RewardCenter {
+    pub const VERSION: u8 = 5;
+
     pub fn size() -> usize {
         8 + // deliminator
+        1 + // version
         32 + // token_mint
         32 + // auction_house
-        1 + 32 + // optional collection oracle
-        2 + 2 + // listing reward rules
-        1 // bump
+        32 + // authority
+        1 + 32 + // optional pending_authority
+        1 + // treasury_mint_decimals
+        1 + // reward_mint_decimals
+        1 + // pause_state
+        8 + // open_listings
+        8 + // open_offers
+        1 + // bump
+        RewardRules::size() + // reward rules
+        1 + RewardRules::size() + // optional candidate reward rules
+        1 + // reward mode
+        8 + // outstanding_rewards
+        1 + // has_uncounted_obligations
+        64 // reserved
+    }
+
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_state & flags != 0
+    }
+
+    /// Whether the collection oracle, when one is set, signed the transaction to approve the
+    /// rewards of a sale.
+    pub fn is_approved_by_oracle(&self, collection_oracle: Option<&AccountInfo>) -> bool {
+        match self.reward_rules.collection_oracle {
+            Some(oracle) => matches!(
+                collection_oracle,
+                Some(signer) if signer.key() == oracle && signer.is_signer
+            ),
+            None => true,
+        }
     }
 
     fn calculate_total_token_payout(
@@ -65,27 +407,155 @@ impl RewardCenter {
         }
     }
 
-    pub fn payouts(&self, listing_price: u64) -> Result<(u64, u64)> {
-        let total_token_payout = self
-            .calculate_total_token_payout(listing_price, &self.reward_rules.mathematical_operand)?;
+    /// Converts an amount expressed in treasury mint units into reward mint units.
+    fn normalize_decimals(&self, amount: u64) -> Result<u64> {
+        let decimal_difference = self
+            .treasury_mint_decimals
+            .abs_diff(self.reward_mint_decimals);
 
-        let seller_share = self.reward_rules.seller_reward_payout_basis_points;
+        let scale = 10u128
+            .checked_pow(decimal_difference.into())
+            .ok_or(RewardCenterError::RewardMintDecimalMismatch)?;
 
-        let seller_payout = (seller_share as u128)
-            .checked_mul(total_token_payout as u128)
-            .and_then(|product| product.checked_div(10000))
-            .ok_or(RewardCenterError::NumericalOverflowError)? as u64;
+        let normalized_amount = if self.reward_mint_decimals >= self.treasury_mint_decimals {
+            (amount as u128).checked_mul(scale)
+        } else {
+            (amount as u128).checked_div(scale)
+        }
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        u64::try_from(normalized_amount)
+            .map_err(|_| RewardCenterError::NumericalOverflowError.into())
+    }
 
-        let buyer_payout = total_token_payout
-            .checked_sub(seller_payout)
+    /// The reward pool of a sale in reward mint units.
+    fn evaluate_reward_curve(&self, listing_price: u64) -> Result<u64> {
+        match &self.reward_rules.reward_curve {
+            RewardCurve::Linear => self.normalize_decimals(self.calculate_total_token_payout(
+                listing_price,
+                &self.reward_rules.mathematical_operand,
+            )?),
+            RewardCurve::Tiered(tiers) => {
+                self.normalize_decimals(RewardCurve::tiered_payout(tiers, listing_price)?)
+            }
+            RewardCurve::Capped { max_reward } => {
+                let total_token_payout =
+                    self.normalize_decimals(self.calculate_total_token_payout(
+                        listing_price,
+                        &self.reward_rules.mathematical_operand,
+                    )?)?;
+
+                Ok(total_token_payout.min(*max_reward))
+            }
+        }
+    }
+
+    /// The payouts of a sale under the candidate reward rules, if any are set.
+    pub fn candidate_payouts(
+        &self,
+        listing_price: u64,
+        sale_path: SalePath,
+        attributed: bool,
+    ) -> Result<Option<(u64, u64, u64)>> {
+        self.candidate_reward_rules
+            .as_ref()
+            .map(|candidate_reward_rules| {
+                self.payouts_under(candidate_reward_rules, listing_price, sale_path, attributed)
+            })
+            .transpose()
+    }
+
+    /// The payouts of a sale under other reward rules, scaled by the decimals of this reward
+    /// center.
+    pub fn payouts_under(
+        &self,
+        reward_rules: &RewardRules,
+        listing_price: u64,
+        sale_path: SalePath,
+        attributed: bool,
+    ) -> Result<(u64, u64, u64)> {
+        RewardCenter {
+            reward_rules: reward_rules.clone(),
+            candidate_reward_rules: None,
+            ..self.clone()
+        }
+        .payouts(listing_price, sale_path, attributed)
+    }
+
+    /// Splits the reward pool of a sale into the seller, buyer and attributor payouts. The
+    /// attributor share is taken off the pool first when the sale is attributed, and the sale
+    /// path decides whether the seller or the buyer gets the maker share.
+    pub fn payouts(
+        &self,
+        listing_price: u64,
+        sale_path: SalePath,
+        attributed: bool,
+    ) -> Result<(u64, u64, u64)> {
+        let total_token_payout = match self.reward_rules.max_reward_per_sale {
+            Some(max_reward_per_sale) => self
+                .evaluate_reward_curve(listing_price)?
+                .min(max_reward_per_sale),
+            None => self.evaluate_reward_curve(listing_price)?,
+        };
+
+        let attributor_payout = if attributed {
+            Self::basis_points_of(
+                self.reward_rules.attributor_reward_basis_points,
+                total_token_payout,
+            )?
+        } else {
+            0
+        };
+
+        let total_token_payout = total_token_payout
+            .checked_sub(attributor_payout)
             .ok_or(RewardCenterError::NumericalOverflowError)?;
 
-        Ok((seller_payout, buyer_payout))
+        let (seller_payout, buyer_payout) = match &self.reward_rules.maker_taker_split {
+            Some(MakerTakerSplit {
+                maker_reward_basis_points,
+                taker_reward_basis_points,
+            }) => {
+                let maker_payout =
+                    Self::basis_points_of(*maker_reward_basis_points, total_token_payout)?;
+                let taker_payout =
+                    Self::basis_points_of(*taker_reward_basis_points, total_token_payout)?;
+
+                match sale_path {
+                    SalePath::BuyListing => (maker_payout, taker_payout),
+                    SalePath::AcceptOffer => (taker_payout, maker_payout),
+                }
+            }
+            None => {
+                let seller_payout = Self::basis_points_of(
+                    self.reward_rules.seller_reward_payout_basis_points,
+                    total_token_payout,
+                )?;
+
+                let buyer_payout = total_token_payout
+                    .checked_sub(seller_payout)
+                    .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+                (seller_payout, buyer_payout)
+            }
+        };
+
+        Ok((seller_payout, buyer_payout, attributor_payout))
+    }
+
+    fn basis_points_of(basis_points: u16, amount: u64) -> Result<u64> {
+        let share = (basis_points as u128)
+            .checked_mul(amount as u128)
+            .and_then(|product| product.checked_div(10000))
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(share as u64)
     }
 }
 
 #[account]
 pub struct Listing {
+    pub version: u8,
     pub reward_center: Pubkey,
     pub seller: Pubkey,
     pub metadata: Pubkey,
@@ -93,23 +563,43 @@ pub struct Listing {
     pub token_size: u64,
     pub bump: u8,
     pub created_at: i64,
+    pub expires_at: Option<i64>,
+    /// last time the price was set, starting at created_at
+    pub updated_at: i64,
+    pub reserved: [u8; 24],
 }
 
 impl Listing {
+    pub const VERSION: u8 = 3;
+
     pub fn size() -> usize {
         8 + // delimiter
+        1 + // version
         32 + // reward_center
         32 + // seller
         32 + // metadata
         8 + // price
         8 + // token_size
         1 + // bump
-        8 // created_at
+        8 + // created_at
+        1 + 8 + // optional expires_at
+        8 + // updated_at
+        24 // reserved
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+
+    /// Whether the listing has been open at its current price for less than min_age seconds.
+    pub fn is_younger_than(&self, now: i64, min_age: i64) -> bool {
+        now.saturating_sub(self.created_at.max(self.updated_at)) < min_age
     }
 }
 
 #[account]
 pub struct Offer {
+    pub version: u8,
     pub reward_center: Pubkey,
     pub buyer: Pubkey,
     pub metadata: Pubkey,
@@ -118,11 +608,18 @@ pub struct Offer {
     pub bump: u8,
     pub created_at: i64,
     pub price_with_fees: u64,
+    pub expires_at: Option<i64>,
+    /// last time the price was set, starting at created_at
+    pub updated_at: i64,
+    pub reserved: [u8; 24],
 }
 
 impl Offer {
+    pub const VERSION: u8 = 3;
+
     pub fn size() -> usize {
         8 + // delimiter
+        1 + // version
         32 + // reward_center
         32 + // buyer
         32 + // metadata
@@ -130,6 +627,814 @@ impl Offer {
         8 + // token_size
         1 + // bump
         8 + // created_at
-        8 // price_with_fees
+        8 + // price_with_fees
+        1 + 8 + // optional expires_at
+        8 + // updated_at
+        24 // reserved
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+
+    /// Whether the offer has been open at its current price for less than min_age seconds.
+    pub fn is_younger_than(&self, now: i64, min_age: i64) -> bool {
+        now.saturating_sub(self.created_at.max(self.updated_at)) < min_age
+    }
+
+    /// The price with fees held in escrow for the offer. Offers placed before `price_with_fees`
+    /// was recorded hold zero or an out of range value, and the escrow behind them is unknown.
+    pub fn escrowed_price_with_fees(&self) -> Option<u64> {
+        if (self.price..=self.price.saturating_mul(3)).contains(&self.price_with_fees) {
+            Some(self.price_with_fees)
+        } else {
+            None
+        }
+    }
+}
+
+#[account]
+pub struct PurchaseTicket {
+    pub reward_center: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    /// rewards paid, vested or owed to the buyer for the sale
+    pub buyer_reward: u64,
+    /// rewards paid, vested or owed to the seller for the sale
+    pub seller_reward: u64,
+    /// wallet that funded the ticket and receives its rent when it is closed
+    pub payer: Pubkey,
+    pub created_at: i64,
+    /// number of the sale among the sales of the NFT, seeding the ticket address
+    pub sale_number: u64,
+    pub bump: u8,
+}
+
+impl PurchaseTicket {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // buyer
+        32 + // seller
+        32 + // metadata
+        8 + // price
+        8 + // token_size
+        8 + // buyer_reward
+        8 + // seller_reward
+        32 + // payer
+        8 + // created_at
+        8 + // sale_number
+        1 // bump
+    }
+}
+
+#[account]
+pub struct SaleCounter {
+    pub reward_center: Pubkey,
+    /// the mint of the NFT sold
+    pub mint: Pubkey,
+    /// number of sales of the NFT so far, numbering the purchase ticket of the next sale
+    pub sale_count: u64,
+    pub bump: u8,
+}
+
+impl SaleCounter {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // mint
+        8 + // sale_count
+        1 // bump
+    }
+}
+
+#[account]
+pub struct SaleRecord {
+    pub reward_center: Pubkey,
+    /// the mint of the NFT sold
+    pub mint: Pubkey,
+    pub last_buyer: Pubkey,
+    pub last_seller: Pubkey,
+    pub last_sale_at: i64,
+    pub bump: u8,
+}
+
+impl SaleRecord {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // mint
+        32 + // last_buyer
+        32 + // last_seller
+        8 + // last_sale_at
+        1 // bump
+    }
+
+    /// Whether a sale looks like a wash trade: the buyer sells to themselves, or within
+    /// window_seconds of the previous sale the NFT is flipped by its last buyer or bought back by
+    /// its last seller.
+    pub fn is_wash_trade(
+        &self,
+        buyer: &Pubkey,
+        seller: &Pubkey,
+        now: i64,
+        window_seconds: i64,
+    ) -> bool {
+        if buyer == seller {
+            return true;
+        }
+
+        let within_window = now.saturating_sub(self.last_sale_at) < window_seconds;
+
+        within_window && (*seller == self.last_buyer || *buyer == self.last_seller)
+    }
+}
+
+#[account]
+pub struct WalletRewardWindow {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    /// the epoch the rewarded amount was earned in
+    pub epoch: u64,
+    /// rewards earned by the wallet during the epoch
+    pub rewarded_amount: u64,
+    pub bump: u8,
+}
+
+impl WalletRewardWindow {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // epoch
+        8 + // rewarded_amount
+        1 // bump
+    }
+
+    /// Credits as much of the reward as fits under max_reward for the epoch, starting over when
+    /// a new epoch has begun. Returns the credited amount.
+    pub fn credit(&mut self, amount: u64, epoch: u64, max_reward: u64) -> u64 {
+        if self.epoch != epoch {
+            self.epoch = epoch;
+            self.rewarded_amount = 0;
+        }
+
+        let credited_amount = amount.min(max_reward.saturating_sub(self.rewarded_amount));
+        self.rewarded_amount = self.rewarded_amount.saturating_add(credited_amount);
+
+        credited_amount
+    }
+}
+
+#[account]
+pub struct RewardableCollection {
+    pub reward_center: Pubkey,
+    pub collection: Pubkey,
+    pub bump: u8,
+}
+
+impl RewardableCollection {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // collection
+        1 // bump
+    }
+
+    /// Checks that the NFT belongs to a verified collection registered as rewardable with the reward center.
+    pub fn is_registered(
+        rewardable_collection: &AccountInfo,
+        reward_center: &Pubkey,
+        metadata: &Metadata,
+    ) -> bool {
+        let collection = match &metadata.collection {
+            Some(collection) if collection.verified => collection.key,
+            _ => return false,
+        };
+
+        match Account::<RewardableCollection>::try_from(rewardable_collection) {
+            Ok(rewardable_collection) => {
+                rewardable_collection.reward_center == *reward_center
+                    && rewardable_collection.collection == collection
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[account]
+pub struct Attributor {
+    pub reward_center: Pubkey,
+    /// wallet receiving the attribution rewards
+    pub wallet: Pubkey,
+    pub bump: u8,
+}
+
+impl Attributor {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        1 // bump
+    }
+}
+
+#[account]
+pub struct SponsorPool {
+    pub reward_center: Pubkey,
+    /// the mint of the Metaplex collection NFT the pool rewards sales of
+    pub collection: Pubkey,
+    /// the sponsor funding the pool, the update authority of the collection when it was created
+    pub authority: Pubkey,
+    pub bump: u8,
+    /// rules for the rewards paid out of the pool
+    pub sponsor_rules: SponsorRules,
+}
+
+impl SponsorPool {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // collection
+        32 + // authority
+        1 + // bump
+        SponsorRules::size() // sponsor rules
+    }
+
+    /// Whether the pool sponsors the verified collection of the NFT.
+    pub fn sponsors(&self, metadata: &Metadata) -> bool {
+        matches!(
+            &metadata.collection,
+            Some(collection) if collection.verified && collection.key == self.collection
+        )
+    }
+}
+
+#[account]
+pub struct RewardDebt {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl RewardDebt {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // amount
+        1 // bump
+    }
+}
+
+#[account]
+pub struct RewardVesting {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    /// rewards vested under earlier schedules and not claimed yet
+    pub carried_amount: u64,
+    /// rewards locked under the current schedule
+    pub schedule_amount: u64,
+    /// rewards of the current schedule already claimed
+    pub schedule_claimed_amount: u64,
+    /// start time of the current schedule
+    pub start_at: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub bump: u8,
+}
+
+impl RewardVesting {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // carried_amount
+        8 + // schedule_amount
+        8 + // schedule_claimed_amount
+        8 + // start_at
+        8 + // cliff
+        8 + // duration
+        1 // bump
+    }
+
+    /// Amount of the current schedule vested at the given time.
+    pub fn schedule_vested_amount(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.start_at);
+
+        if elapsed < self.cliff {
+            return Ok(0);
+        }
+
+        if self.duration <= 0 || elapsed >= self.duration {
+            return Ok(self.schedule_amount);
+        }
+
+        let vested = (self.schedule_amount as u128)
+            .checked_mul(elapsed as u128)
+            .and_then(|product| product.checked_div(self.duration as u128))
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(vested as u64)
+    }
+
+    /// Amount that can be claimed at the given time.
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        // A later weighted start can put the vested amount back under the claimed amount
+        self.schedule_vested_amount(now)?
+            .saturating_sub(self.schedule_claimed_amount)
+            .checked_add(self.carried_amount)
+            .ok_or(RewardCenterError::NumericalOverflowError.into())
+    }
+
+    /// Adds rewards to the vesting account. While the current schedule still has locked rewards,
+    /// the new rewards join it and its start moves to the average of the earlier start and now,
+    /// weighted by amount, so the rewards already credited keep most of their progress. Otherwise
+    /// the vested rewards stay claimable and the new rewards start a schedule of their own.
+    pub fn credit(&mut self, amount: u64, now: i64, cliff: i64, duration: i64) -> Result<()> {
+        if self.schedule_vested_amount(now)? < self.schedule_amount {
+            let schedule_amount = self
+                .schedule_amount
+                .checked_add(amount)
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+            let weighted_start_at = i128::from(self.start_at)
+                .checked_mul(i128::from(self.schedule_amount))
+                .zip(i128::from(now).checked_mul(i128::from(amount)))
+                .and_then(|(earlier, later)| earlier.checked_add(later))
+                .and_then(|total| total.checked_div(i128::from(schedule_amount)))
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+            self.schedule_amount = schedule_amount;
+            self.start_at = weighted_start_at as i64;
+        } else {
+            self.carried_amount = self.claimable_amount(now)?;
+            self.schedule_amount = amount;
+            self.schedule_claimed_amount = 0;
+            self.start_at = now;
+        }
+
+        self.cliff = cliff;
+        self.duration = duration;
+
+        Ok(())
+    }
+
+    /// Marks everything claimable at the given time as claimed and returns that amount.
+    pub fn claim(&mut self, now: i64) -> Result<u64> {
+        let claimable_amount = self.claimable_amount(now)?;
+
+        self.schedule_claimed_amount = self
+            .schedule_claimed_amount
+            .max(self.schedule_vested_amount(now)?);
+        self.carried_amount = 0;
+
+        Ok(claimable_amount)
+    }
+
+    /// Whether every credited reward has been claimed.
+    pub fn is_settled(&self) -> bool {
+        self.carried_amount == 0 && self.schedule_claimed_amount == self.schedule_amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reward_center(reward_curve: RewardCurve) -> RewardCenter {
+        RewardCenter {
+            version: RewardCenter::VERSION,
+            token_mint: Pubkey::default(),
+            auction_house: Pubkey::default(),
+            authority: Pubkey::default(),
+            pending_authority: None,
+            treasury_mint_decimals: 9,
+            reward_mint_decimals: 9,
+            pause_state: 0,
+            open_listings: 0,
+            open_offers: 0,
+            bump: 0,
+            reward_rules: RewardRules {
+                seller_reward_payout_basis_points: 5000,
+                mathematical_operand: PayoutOperation::Multiple,
+                payout_numeral: 2,
+                payout_mode: PayoutMode::Immediate,
+                attributor_reward_basis_points: 0,
+                collection_oracle: None,
+                maker_taker_split: None,
+                reward_curve,
+                wash_trade_window_seconds: 0,
+                min_order_age_seconds: 0,
+                max_reward_per_sale: None,
+                max_reward_per_wallet_per_epoch: None,
+            },
+            candidate_reward_rules: None,
+            reward_mode: RewardMode::Live,
+            outstanding_rewards: 0,
+            has_uncounted_obligations: false,
+            reserved: [0; 64],
+        }
+    }
+
+    fn tier(price_threshold: u64, numerator: u16, denominator: u16) -> RewardTier {
+        RewardTier {
+            price_threshold,
+            numerator,
+            denominator,
+        }
+    }
+
+    fn total_payout(reward_center: &RewardCenter, listing_price: u64) -> u64 {
+        let (seller_payout, buyer_payout, _) = reward_center
+            .payouts(listing_price, SalePath::BuyListing, false)
+            .unwrap();
+
+        seller_payout + buyer_payout
+    }
+
+    fn error_code(error: Error) -> u32 {
+        match error {
+            Error::AnchorError(error) => error.error_code_number,
+            Error::ProgramError(error) => panic!("Unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn linear_curve_scales_the_whole_price() {
+        let reward_center = reward_center(RewardCurve::Linear);
+
+        assert_eq!(total_payout(&reward_center, 1_000), 2_000);
+        assert_eq!(total_payout(&reward_center, 1_000_000), 2_000_000);
+    }
+
+    #[test]
+    fn tiered_curve_rewards_each_part_of_the_price_at_its_tier_rate() {
+        let tiers = [tier(0, 2, 1), tier(1_000, 1, 1), tier(10_000, 1, 10)];
+
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 0).unwrap(), 0);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 500).unwrap(), 1_000);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 1_000).unwrap(), 2_000);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 4_000).unwrap(), 5_000);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 10_000).unwrap(), 11_000);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 30_000).unwrap(), 13_000);
+    }
+
+    #[test]
+    fn tiered_curve_pays_nothing_below_the_first_threshold() {
+        let tiers = [tier(1_000, 1, 2)];
+
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 999).unwrap(), 0);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 1_000).unwrap(), 0);
+        assert_eq!(RewardCurve::tiered_payout(&tiers, 3_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn tiered_curve_handles_the_largest_prices() {
+        let tiers = [tier(0, u16::MAX, 1)];
+
+        assert!(RewardCurve::tiered_payout(&tiers, u64::MAX).is_err());
+        assert_eq!(
+            RewardCurve::tiered_payout(&[tier(0, 1, u16::MAX)], u64::MAX).unwrap(),
+            u64::MAX / u64::from(u16::MAX)
+        );
+    }
+
+    #[test]
+    fn tiered_curve_is_evaluated_by_payouts() {
+        let reward_center =
+            reward_center(RewardCurve::Tiered(vec![tier(0, 2, 1), tier(1_000, 1, 10)]));
+
+        assert_eq!(total_payout(&reward_center, 500), 1_000);
+        assert_eq!(total_payout(&reward_center, 11_000), 3_000);
+    }
+
+    #[test]
+    fn capped_curve_limits_the_linear_reward() {
+        let reward_center = reward_center(RewardCurve::Capped { max_reward: 5_000 });
+
+        assert_eq!(total_payout(&reward_center, 1_000), 2_000);
+        assert_eq!(total_payout(&reward_center, 2_500), 5_000);
+        assert_eq!(total_payout(&reward_center, 1_000_000), 5_000);
+    }
+
+    #[test]
+    fn capped_curve_limits_the_normalized_reward() {
+        let mut reward_center = reward_center(RewardCurve::Capped { max_reward: 5_000 });
+        reward_center.reward_mint_decimals = 6;
+
+        assert_eq!(total_payout(&reward_center, 1_000_000), 2_000);
+        assert_eq!(total_payout(&reward_center, 1_000_000_000), 5_000);
+    }
+
+    #[test]
+    fn invalid_tiers_are_rejected() {
+        let invalid_curves = [
+            RewardCurve::Tiered(vec![]),
+            RewardCurve::Tiered(vec![tier(0, 1, 1); MAX_REWARD_TIERS + 1]),
+            RewardCurve::Tiered(vec![tier(1_000, 1, 1), tier(1_000, 1, 2)]),
+            RewardCurve::Tiered(vec![tier(1_000, 1, 1), tier(0, 1, 2)]),
+            RewardCurve::Tiered(vec![tier(0, 1, 0)]),
+        ];
+
+        for reward_curve in invalid_curves {
+            assert_eq!(
+                error_code(reward_curve.validate().unwrap_err()),
+                u32::from(RewardCenterError::InvalidRewardCurve)
+            );
+        }
+
+        let tiers = (0..MAX_REWARD_TIERS as u64)
+            .map(|index| tier(index * 1_000, 1, 1))
+            .collect();
+
+        assert!(RewardCurve::Tiered(tiers).validate().is_ok());
+        assert!(RewardCurve::Linear.validate().is_ok());
+        assert!(RewardCurve::Capped { max_reward: 0 }.validate().is_ok());
+    }
+
+    #[test]
+    fn reward_center_fits_the_largest_curve() {
+        let mut reward_center =
+            reward_center(RewardCurve::Tiered(vec![
+                tier(u64::MAX, u16::MAX, u16::MAX);
+                MAX_REWARD_TIERS
+            ]));
+        reward_center.pending_authority = Some(Pubkey::default());
+        reward_center.reward_rules.payout_mode = PayoutMode::Vested {
+            cliff: 0,
+            duration: 0,
+        };
+        reward_center.reward_rules.collection_oracle = Some(Pubkey::default());
+        reward_center.reward_rules.maker_taker_split = Some(MakerTakerSplit {
+            maker_reward_basis_points: 0,
+            taker_reward_basis_points: 0,
+        });
+        reward_center.reward_rules.max_reward_per_sale = Some(0);
+        reward_center.reward_rules.max_reward_per_wallet_per_epoch = Some(0);
+        reward_center.candidate_reward_rules = Some(reward_center.reward_rules.clone());
+        reward_center.reward_mode = RewardMode::Shadow;
+
+        let mut data = Vec::new();
+        reward_center.try_serialize(&mut data).unwrap();
+
+        assert_eq!(data.len(), RewardCenter::size());
+    }
+
+    #[test]
+    fn sponsor_pool_fits_the_largest_sponsor_rules() {
+        let sponsor_rules = SponsorRules {
+            seller_reward_payout_basis_points: 0,
+            mathematical_operand: PayoutOperation::Multiple,
+            payout_numeral: 0,
+            maker_taker_split: Some(MakerTakerSplit {
+                maker_reward_basis_points: 0,
+                taker_reward_basis_points: 0,
+            }),
+            reward_curve: RewardCurve::Tiered(vec![
+                tier(u64::MAX, u16::MAX, u16::MAX);
+                MAX_REWARD_TIERS
+            ]),
+            max_reward_per_sale: Some(0),
+        };
+
+        let sponsor_pool = SponsorPool {
+            reward_center: Pubkey::default(),
+            collection: Pubkey::default(),
+            authority: Pubkey::default(),
+            bump: 0,
+            sponsor_rules,
+        };
+
+        let mut data = Vec::new();
+        sponsor_pool.try_serialize(&mut data).unwrap();
+
+        assert_eq!(data.len(), SponsorPool::size());
+    }
+
+    #[test]
+    fn candidate_payouts_use_the_candidate_reward_rules() {
+        let mut reward_center = reward_center(RewardCurve::Linear);
+
+        assert_eq!(
+            reward_center
+                .candidate_payouts(1_000, SalePath::BuyListing, false)
+                .unwrap(),
+            None
+        );
+
+        let mut candidate_reward_rules = reward_center.reward_rules.clone();
+        candidate_reward_rules.reward_curve = RewardCurve::Capped { max_reward: 1_000 };
+        reward_center.candidate_reward_rules = Some(candidate_reward_rules);
+
+        assert_eq!(
+            reward_center
+                .candidate_payouts(1_000, SalePath::BuyListing, false)
+                .unwrap(),
+            Some((500, 500, 0))
+        );
+        assert_eq!(total_payout(&reward_center, 1_000), 2_000);
+    }
+
+    fn sale_record(last_buyer: Pubkey, last_seller: Pubkey, last_sale_at: i64) -> SaleRecord {
+        SaleRecord {
+            reward_center: Pubkey::default(),
+            mint: Pubkey::default(),
+            last_buyer,
+            last_seller,
+            last_sale_at,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn self_trades_are_wash_trades() {
+        let wallet = Pubkey::new_unique();
+        let sale_record = sale_record(Pubkey::default(), Pubkey::default(), 0);
+
+        assert!(sale_record.is_wash_trade(&wallet, &wallet, 1_000, 0));
+    }
+
+    #[test]
+    fn round_trips_within_the_window_are_wash_trades() {
+        let first_wallet = Pubkey::new_unique();
+        let second_wallet = Pubkey::new_unique();
+        let other_wallet = Pubkey::new_unique();
+        let sale_record = sale_record(second_wallet, first_wallet, 1_000);
+
+        // Bought back by the previous seller
+        assert!(sale_record.is_wash_trade(&first_wallet, &second_wallet, 1_059, 60));
+        assert!(sale_record.is_wash_trade(&first_wallet, &other_wallet, 1_059, 60));
+
+        // Flipped by the previous buyer
+        assert!(sale_record.is_wash_trade(&other_wallet, &second_wallet, 1_059, 60));
+
+        // Unrelated wallets
+        assert!(!sale_record.is_wash_trade(&other_wallet, &first_wallet, 1_059, 60));
+    }
+
+    #[test]
+    fn round_trips_after_the_window_are_not_wash_trades() {
+        let first_wallet = Pubkey::new_unique();
+        let second_wallet = Pubkey::new_unique();
+        let sale_record = sale_record(second_wallet, first_wallet, 1_000);
+
+        assert!(!sale_record.is_wash_trade(&first_wallet, &second_wallet, 1_060, 60));
+        assert!(!sale_record.is_wash_trade(&first_wallet, &second_wallet, 1_000, 0));
+    }
+
+    #[test]
+    fn negative_wash_trade_windows_are_rejected() {
+        let mut reward_center = reward_center(RewardCurve::Linear);
+        reward_center.reward_rules.wash_trade_window_seconds = -1;
+
+        assert_eq!(
+            error_code(reward_center.reward_rules.validate().unwrap_err()),
+            u32::from(RewardCenterError::InvalidWashTradeWindow)
+        );
+    }
+
+    #[test]
+    fn max_reward_per_sale_limits_the_reward_pool() {
+        let mut reward_center = reward_center(RewardCurve::Linear);
+        reward_center.reward_rules.max_reward_per_sale = Some(5_000);
+
+        assert_eq!(total_payout(&reward_center, 1_000), 2_000);
+        assert_eq!(total_payout(&reward_center, 1_000_000), 5_000);
+
+        reward_center.reward_rules.attributor_reward_basis_points = 1_000;
+
+        let (seller_payout, buyer_payout, attributor_payout) = reward_center
+            .payouts(1_000_000, SalePath::BuyListing, true)
+            .unwrap();
+
+        assert_eq!(attributor_payout, 500);
+        assert_eq!(seller_payout + buyer_payout, 4_500);
+    }
+
+    fn wallet_reward_window(epoch: u64, rewarded_amount: u64) -> WalletRewardWindow {
+        WalletRewardWindow {
+            reward_center: Pubkey::default(),
+            wallet: Pubkey::default(),
+            epoch,
+            rewarded_amount,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn wallet_rewards_are_capped_within_an_epoch() {
+        let mut wallet_reward_window = wallet_reward_window(3, 0);
+
+        assert_eq!(wallet_reward_window.credit(600, 3, 1_000), 600);
+        assert_eq!(wallet_reward_window.credit(600, 3, 1_000), 400);
+        assert_eq!(wallet_reward_window.credit(600, 3, 1_000), 0);
+        assert_eq!(wallet_reward_window.rewarded_amount, 1_000);
+    }
+
+    #[test]
+    fn wallet_rewards_start_over_in_a_new_epoch() {
+        let mut wallet_reward_window = wallet_reward_window(3, 1_000);
+
+        assert_eq!(wallet_reward_window.credit(600, 4, 1_000), 600);
+        assert_eq!(wallet_reward_window.epoch, 4);
+        assert_eq!(wallet_reward_window.rewarded_amount, 600);
+    }
+
+    #[test]
+    fn wallet_rewards_stay_capped_when_the_cap_is_lowered() {
+        let mut wallet_reward_window = wallet_reward_window(3, 1_000);
+
+        assert_eq!(wallet_reward_window.credit(600, 3, 500), 0);
+        assert_eq!(wallet_reward_window.rewarded_amount, 1_000);
+    }
+
+    fn reward_vesting() -> RewardVesting {
+        RewardVesting {
+            reward_center: Pubkey::default(),
+            wallet: Pubkey::default(),
+            carried_amount: 0,
+            schedule_amount: 0,
+            schedule_claimed_amount: 0,
+            start_at: 0,
+            cliff: 0,
+            duration: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn credits_keep_the_progress_of_the_locked_rewards() {
+        let mut reward_vesting = reward_vesting();
+
+        reward_vesting.credit(900, 0, 0, 1_000).unwrap();
+        assert_eq!(reward_vesting.claim(500).unwrap(), 450);
+
+        reward_vesting.credit(100, 500, 0, 1_000).unwrap();
+
+        assert_eq!(reward_vesting.start_at, 50);
+        assert_eq!(reward_vesting.schedule_amount, 1_000);
+        assert_eq!(reward_vesting.claimable_amount(500).unwrap(), 0);
+        assert_eq!(reward_vesting.claimable_amount(600).unwrap(), 100);
+        assert_eq!(reward_vesting.claimable_amount(1_050).unwrap(), 550);
+    }
+
+    #[test]
+    fn credits_after_full_vesting_start_a_new_schedule() {
+        let mut reward_vesting = reward_vesting();
+
+        reward_vesting.credit(900, 0, 0, 1_000).unwrap();
+        reward_vesting.credit(100, 2_000, 0, 1_000).unwrap();
+
+        assert_eq!(reward_vesting.start_at, 2_000);
+        assert_eq!(reward_vesting.carried_amount, 900);
+        assert_eq!(reward_vesting.claimable_amount(2_000).unwrap(), 900);
+        assert_eq!(reward_vesting.claimable_amount(2_500).unwrap(), 950);
+    }
+
+    #[test]
+    fn claims_never_go_back_under_a_later_cliff() {
+        let mut reward_vesting = reward_vesting();
+
+        reward_vesting.credit(100, 0, 0, 1_000).unwrap();
+        assert_eq!(reward_vesting.claim(500).unwrap(), 50);
+
+        reward_vesting.credit(900, 500, 100, 1_000).unwrap();
+
+        assert_eq!(reward_vesting.start_at, 450);
+        assert_eq!(reward_vesting.claim(520).unwrap(), 0);
+        assert_eq!(reward_vesting.schedule_claimed_amount, 50);
+        assert_eq!(reward_vesting.claim(550).unwrap(), 50);
+    }
+
+    #[test]
+    fn repriced_listings_age_from_their_update() {
+        let listing = Listing {
+            version: Listing::VERSION,
+            reward_center: Pubkey::default(),
+            seller: Pubkey::default(),
+            metadata: Pubkey::default(),
+            price: 1,
+            token_size: 1,
+            bump: 0,
+            created_at: 0,
+            expires_at: None,
+            updated_at: 0,
+            reserved: [0; 24],
+        };
+
+        assert!(!listing.is_younger_than(100, 60));
+
+        let repriced_listing = Listing {
+            updated_at: 90,
+            ..listing
+        };
+
+        assert!(repriced_listing.is_younger_than(100, 60));
+        assert!(!repriced_listing.is_younger_than(150, 60));
     }
 }
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::WALLET_REWARD_WINDOW, errors::RewardCenterError, state::WalletRewardWindow,
+    utils::create_pda_account,
+};
+
+/// Accounts used to hold the rewards of a wallet under the per epoch cap.
+pub struct WalletRewardWindowUpdate<'info> {
+    /// only needed while rewards are capped per wallet
+    pub wallet_reward_window: Option<AccountInfo<'info>>,
+    pub wallet_reward_window_bump: Option<u8>,
+    pub reward_center: Pubkey,
+    /// wallet receiving the reward
+    pub wallet: Pubkey,
+    /// signer funding the wallet reward window when it has to be created
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> WalletRewardWindowUpdate<'info> {
+    /// Clamps the reward to what the wallet can still earn in the current epoch and records it,
+    /// creating the wallet reward window on the first capped reward of the wallet. Rewards are
+    /// left untouched when there is no cap.
+    pub fn clamp(&self, amount: u64, max_reward_per_wallet_per_epoch: Option<u64>) -> Result<u64> {
+        let max_reward = match max_reward_per_wallet_per_epoch {
+            Some(max_reward) => max_reward,
+            None => return Ok(amount),
+        };
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let (wallet_reward_window_account, wallet_reward_window_bump) = self
+            .wallet_reward_window
+            .as_ref()
+            .zip(self.wallet_reward_window_bump)
+            .ok_or(RewardCenterError::WalletRewardWindowMissing)?;
+
+        let epoch = Clock::get()?.epoch;
+
+        if wallet_reward_window_account.data_is_empty() {
+            create_pda_account(
+                wallet_reward_window_account,
+                WalletRewardWindow::size(),
+                &self.payer,
+                &self.system_program,
+                &[
+                    WALLET_REWARD_WINDOW.as_bytes(),
+                    self.reward_center.as_ref(),
+                    self.wallet.as_ref(),
+                    &[wallet_reward_window_bump],
+                ],
+            )?;
+
+            WalletRewardWindow {
+                reward_center: self.reward_center,
+                wallet: self.wallet,
+                epoch,
+                rewarded_amount: 0,
+                bump: wallet_reward_window_bump,
+            }
+            .try_serialize(&mut &mut wallet_reward_window_account.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut wallet_reward_window = WalletRewardWindow::try_deserialize(
+            &mut &wallet_reward_window_account.try_borrow_data()?[..],
+        )?;
+
+        require_keys_eq!(
+            wallet_reward_window.wallet,
+            self.wallet,
+            RewardCenterError::WalletRewardWindowMismatch
+        );
+
+        let credited_amount = wallet_reward_window.credit(amount, epoch, max_reward);
+
+        wallet_reward_window
+            .try_serialize(&mut &mut wallet_reward_window_account.try_borrow_mut_data()?[..])?;
+
+        if credited_amount < amount {
+            msg!(
+                "Wallet reward cap reached, paying {} of {}",
+                credited_amount,
+                amount
+            );
+        }
+
+        Ok(credited_amount)
+    }
+}
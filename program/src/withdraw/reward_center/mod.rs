@@ -22,7 +22,7 @@ pub struct WithdrawRewardCenterFunds<'info> {
     #[
       account(
         mut,
-        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+        constraint = wallet.key() == reward_center.authority @ RewardCenterError::SignerNotAuthorized
       )
     ]
     pub wallet: Signer<'info>,
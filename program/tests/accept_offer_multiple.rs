@@ -2,11 +2,14 @@
 
 pub mod reward_center_test;
 use anchor_client::solana_sdk::{
-    instruction::AccountMeta, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    instruction::AccountMeta, signature::Signer, transaction::Transaction,
 };
 use mtly_auction_house::{pda::find_auction_house_address, AuthorityScope};
 use mtly_reward_center::{pda::find_reward_center_address, reward_centers, state::*};
-use reward_center_test::{fixtures::metadata, get_account};
+use reward_center_test::{
+    fixtures::{collection, metadata},
+    get_account,
+};
 
 use mtly_reward_center_sdk::{
     accept_offer,
@@ -18,7 +21,7 @@ use mtly_reward_center_sdk::{
 use mpl_testing_utils::solana::airdrop;
 use solana_program_test::*;
 use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
-use std::{assert, str::FromStr};
+use std::assert;
 
 use mpl_token_metadata::state::Collection;
 
@@ -36,7 +39,8 @@ async fn accept_offer_multiple_success() {
     let rent = context.banks_client.get_rent().await.unwrap();
     let wallet = context.payer.pubkey();
     let mint = native_mint::id();
-    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+    let collection_metadata = collection::create(&mut context).await;
+    let collection = collection_metadata.mint.pubkey();
 
     let metadata = metadata::create(
         &mut context,
@@ -56,6 +60,10 @@ async fn accept_offer_multiple_success() {
     )
     .await;
 
+    collection::verify(&mut context, &metadata, &collection_metadata)
+        .await
+        .unwrap();
+
     let metadata_owner = metadata.token;
     let metadata_address = metadata.pubkey;
     let metadata_owner_address = metadata_owner.pubkey();
@@ -118,11 +126,11 @@ async fn accept_offer_multiple_success() {
     let payout_numeral = 5;
     let seller_reward_payout_basis_points = 1000;
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Multiple,
+        reward_rules: reward_center_test::reward_rules(
+            PayoutOperation::Multiple,
             seller_reward_payout_basis_points,
             payout_numeral,
-        },
+        ),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -154,6 +162,8 @@ async fn accept_offer_multiple_success() {
         reward_center_params,
     );
 
+    let add_rewardable_collection_ix = add_rewardable_collection(wallet, auction_house, collection);
+
     let delegate_auctioneer_accounts = mtly_auction_house_sdk::DelegateAuctioneerAccounts {
         auction_house,
         authority: wallet,
@@ -186,6 +196,7 @@ async fn accept_offer_multiple_success() {
             allocate_reward_mint_space_ix,
             init_rewards_reward_mint_ix,
             create_reward_center_ix,
+            add_rewardable_collection_ix,
             mint_reward_tokens_ix,
             delegate_auctioneer_ix,
         ],
@@ -227,6 +238,7 @@ async fn accept_offer_multiple_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: offer_price,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -272,8 +284,15 @@ async fn accept_offer_multiple_success() {
 
     let accept_offer_params = AcceptOfferData {
         price: reward_center_test::ONE_SOL,
+        min_price: reward_center_test::ONE_SOL,
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: Some(collection),
+        attributor: None,
+        collection_oracle: None,
+        sponsored: false,
+        sale_number: 0,
+        reward_accounts: SaleRewardAccounts::default(),
     };
 
     let accept_offer_ix = accept_offer(
@@ -283,11 +302,18 @@ async fn accept_offer_multiple_success() {
     );
 
     let tx = Transaction::new_signed_with_payer(
-        &[
-            create_buyer_reward_token_ix,
-            create_seller_reward_token_ix,
-            accept_offer_ix,
-        ],
+        &[create_buyer_reward_token_ix, create_seller_reward_token_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_offer_ix],
         Some(&metadata_owner_address),
         &[&metadata_owner],
         context.last_blockhash,
@@ -2,7 +2,14 @@
 
 pub mod reward_center_test;
 use anchor_client::solana_sdk::{
-    instruction::AccountMeta, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::AccountMeta,
+    message::{v0, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::{Transaction, VersionedTransaction},
 };
 use mtly_auction_house::{
     pda::{
@@ -128,11 +135,7 @@ async fn buy_listing_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 500,
-            payout_numeral: 7,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 500, 7),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -228,6 +231,7 @@ async fn buy_listing_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -307,14 +311,19 @@ async fn buy_listing_success() {
     };
 
     let listing_price = reward_center_test::ONE_SOL * 7;
-    let _price = listing_price
-        + ((ah_sfbp as u64 * listing_price) / 10000)
-        + ((sfbp as u64 * listing_price) / 10000);
+    let price = reward_center_test::price_with_fees(listing_price, ah_sfbp, sfbp);
 
     let buy_listing_params = BuyListingData {
         price: listing_price,
+        max_price: price,
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: None,
+        attributor: None,
+        collection_oracle: None,
+        sponsored: false,
+        sale_number: 0,
+        reward_accounts: SaleRewardAccounts::default(),
     };
 
     let buy_listing_ix = buy_listing(
@@ -324,19 +333,109 @@ async fn buy_listing_success() {
     );
 
     let tx = Transaction::new_signed_with_payer(
-        &[
-            create_buyer_reward_token_ix,
-            create_seller_reward_token_ix,
-            buy_listing_ix,
-        ],
+        &[create_buyer_reward_token_ix, create_seller_reward_token_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[buy_listing_ix],
         Some(&buyer_pubkey),
-        &[&context.payer, &buyer],
+        &[&buyer],
         context.last_blockhash,
     );
 
+    assert!(bincode::serialize(&tx).unwrap().len() <= PACKET_DATA_SIZE);
+
     let tx_response = context.banks_client.process_transaction(tx).await;
 
     assert!(tx_response.is_ok());
 
     ()
 }
+
+#[test]
+fn buy_listing_with_all_reward_accounts_fits_in_a_transaction() {
+    let buyer = Keypair::new();
+    let seller = Pubkey::new_unique();
+    let auction_house = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let treasury_mint = native_mint::id();
+    let reward_mint = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let collection_oracle = Keypair::new();
+
+    let buy_listing_accounts = BuyListingAccounts {
+        auction_house,
+        token_account: get_associated_token_address(&seller, &token_mint),
+        buyer: buyer.pubkey(),
+        transfer_authority: buyer.pubkey(),
+        payment_account: buyer.pubkey(),
+        seller,
+        authority,
+        token_mint,
+        treasury_mint,
+        buyer_receipt_token_account: get_associated_token_address(&buyer.pubkey(), &token_mint),
+        seller_payment_receipt_account: seller,
+        metadata: Pubkey::new_unique(),
+    };
+
+    let buy_listing_params = BuyListingData {
+        price: reward_center_test::ONE_SOL,
+        max_price: reward_center_test::price_with_fees(reward_center_test::ONE_SOL, 100, 10),
+        token_size: 1,
+        reward_mint,
+        collection: Some(collection),
+        attributor: Some(Pubkey::new_unique()),
+        collection_oracle: Some(collection_oracle.pubkey()),
+        sponsored: true,
+        sale_number: 0,
+        // Reward debt is only owed in immediate payout mode, vesting only in vested payout mode
+        reward_accounts: SaleRewardAccounts {
+            reward_debt: true,
+            reward_vesting: false,
+            sale_record: true,
+            reward_window: true,
+        },
+    };
+
+    let buy_listing_ix = buy_listing(
+        buy_listing_accounts,
+        buy_listing_params,
+        (0..5)
+            .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+            .collect(),
+    );
+
+    // The accounts shared by the sales of the auction house and the collection are looked up
+    let lookup_table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: find_sale_lookup_table_addresses(
+            &auction_house,
+            &authority,
+            &treasury_mint,
+            &reward_mint,
+            Some(&collection),
+        ),
+    };
+
+    let message = v0::Message::try_compile(
+        &buyer.pubkey(),
+        &[buy_listing_ix],
+        &[lookup_table],
+        Hash::default(),
+    )
+    .unwrap();
+
+    let tx =
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[&buyer, &collection_oracle])
+            .unwrap();
+
+    assert!(bincode::serialize(&tx).unwrap().len() <= PACKET_DATA_SIZE);
+}
@@ -2,7 +2,7 @@
 
 pub mod reward_center_test;
 use anchor_client::solana_sdk::{
-    instruction::AccountMeta, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    instruction::AccountMeta, signature::Signer, transaction::Transaction,
 };
 use mtly_auction_house::{
     pda::{
@@ -16,7 +16,10 @@ use mtly_reward_center::{
     reward_centers,
     state::*,
 };
-use reward_center_test::{fixtures::metadata, get_account};
+use reward_center_test::{
+    fixtures::{collection, metadata},
+    get_account,
+};
 
 use mtly_reward_center_sdk::{
     accounts::{BuyListingAccounts, *},
@@ -27,7 +30,7 @@ use mtly_reward_center_sdk::{
 use mpl_testing_utils::solana::airdrop;
 use solana_program_test::*;
 use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
-use std::{assert, str::FromStr};
+use std::assert;
 
 use mpl_token_metadata::state::Collection;
 
@@ -45,7 +48,8 @@ async fn buy_listing_multiple_success() {
     let rent = context.banks_client.get_rent().await.unwrap();
     let wallet = context.payer.pubkey();
     let mint = native_mint::id();
-    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+    let collection_metadata = collection::create(&mut context).await;
+    let collection = collection_metadata.mint.pubkey();
 
     let metadata = metadata::create(
         &mut context,
@@ -65,6 +69,10 @@ async fn buy_listing_multiple_success() {
     )
     .await;
 
+    collection::verify(&mut context, &metadata, &collection_metadata)
+        .await
+        .unwrap();
+
     let metadata_owner = metadata.token;
     let metadata_address = metadata.pubkey;
     let metadata_owner_address = metadata_owner.pubkey();
@@ -129,11 +137,11 @@ async fn buy_listing_multiple_success() {
     let payout_numeral = 5;
     let seller_reward_payout_basis_points = 1000;
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Multiple,
+        reward_rules: reward_center_test::reward_rules(
+            PayoutOperation::Multiple,
             seller_reward_payout_basis_points,
             payout_numeral,
-        },
+        ),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -165,6 +173,8 @@ async fn buy_listing_multiple_success() {
         reward_center_params,
     );
 
+    let add_rewardable_collection_ix = add_rewardable_collection(wallet, auction_house, collection);
+
     let delegate_auctioneer_accounts = mtly_auction_house_sdk::DelegateAuctioneerAccounts {
         auction_house,
         authority: wallet,
@@ -228,6 +238,7 @@ async fn buy_listing_multiple_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -238,6 +249,7 @@ async fn buy_listing_multiple_success() {
             allocate_reward_mint_space_ix,
             init_rewards_reward_mint_ix,
             create_reward_center_ix,
+            add_rewardable_collection_ix,
             mint_reward_tokens_ix,
             delegate_auctioneer_ix,
         ],
@@ -308,8 +320,15 @@ async fn buy_listing_multiple_success() {
 
     let buy_listing_params = BuyListingData {
         price: reward_center_test::ONE_SOL,
+        max_price: reward_center_test::price_with_fees(reward_center_test::ONE_SOL, 100, 10),
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: Some(collection),
+        attributor: None,
+        collection_oracle: None,
+        sponsored: false,
+        sale_number: 0,
+        reward_accounts: SaleRewardAccounts::default(),
     };
 
     let buy_listing_ix = buy_listing(
@@ -319,13 +338,20 @@ async fn buy_listing_multiple_success() {
     );
 
     let tx = Transaction::new_signed_with_payer(
-        &[
-            create_buyer_reward_token_ix,
-            create_seller_reward_token_ix,
-            buy_listing_ix,
-        ],
+        &[create_buyer_reward_token_ix, create_seller_reward_token_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[buy_listing_ix],
         Some(&buyer_pubkey),
-        &[&context.payer, &buyer],
+        &[&buyer],
         context.last_blockhash,
     );
 
@@ -0,0 +1,341 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use mtly_auction_house::{pda::find_auction_house_address, AuthorityScope};
+use mtly_reward_center::{
+    errors::RewardCenterError,
+    pda::{find_offer_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use reward_center_test::{fixtures::metadata, get_account};
+
+use mtly_reward_center_sdk::{
+    accounts::{CloseExpiredOfferAccounts, *},
+    args::{CloseOfferData, *},
+    *,
+};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
+use std::str::FromStr;
+
+use mpl_token_metadata::state::Collection;
+
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::Mint,
+};
+
+#[tokio::test]
+async fn close_expired_offer_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+
+    let metadata = metadata::create(
+        &mut context,
+        metadata::Params {
+            name: "Test",
+            symbol: "TST",
+            uri: "https://nfts.exp.com/1.json",
+            seller_fee_basis_points: 10,
+            is_mutable: false,
+            collection: Some(Collection {
+                verified: false,
+                key: collection,
+            }),
+            uses: None,
+        },
+        None,
+    )
+    .await;
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
+    };
+
+    let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mtly_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mtly_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = mtly_reward_center_sdk::create_reward_center(
+        mtly_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_accounts = mtly_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mtly_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mtly_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let token_account =
+        get_associated_token_address(&metadata_owner_address, &metadata_mint_address);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CREATE OFFER TEST
+
+    let buyer = Keypair::new();
+    let buyer_pubkey = &buyer.pubkey();
+    airdrop(&mut context, buyer_pubkey, reward_center_test::TEN_SOL)
+        .await
+        .unwrap();
+
+    let create_offer_accounts = CreateOfferAccounts {
+        wallet: *buyer_pubkey,
+        transfer_authority: *buyer_pubkey,
+        payment_account: *buyer_pubkey,
+        treasury_mint: mint,
+        token_mint: metadata_mint_address,
+        auction_house,
+        reward_center,
+        token_account,
+        metadata: metadata_address,
+        authority: wallet,
+    };
+
+    let clock = reward_center_test::get_clock(&mut context).await;
+    let offer_duration = 60;
+
+    let create_offer_params = CreateOfferData {
+        token_size: 1,
+        buyer_price: reward_center_test::ONE_SOL,
+        expires_at: Some(clock.unix_timestamp + offer_duration),
+    };
+
+    let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_offer_ix],
+        Some(buyer_pubkey),
+        &[&buyer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CLOSE UNEXPIRED OFFER TEST
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), reward_center_test::ONE_SOL)
+        .await
+        .unwrap();
+
+    let close_expired_offer_accounts = CloseExpiredOfferAccounts {
+        payer: cranker.pubkey(),
+        buyer: *buyer_pubkey,
+        treasury_mint: mint,
+        token_mint: metadata_mint_address,
+        token_account,
+        receipt_account: *buyer_pubkey,
+        metadata: metadata_address,
+        authority: wallet,
+        auction_house,
+        reward_center,
+    };
+
+    let close_expired_offer_params = CloseOfferData {
+        token_size: 1,
+        buyer_price: reward_center_test::ONE_SOL,
+    };
+
+    let close_expired_offer_ix =
+        close_expired_offer(close_expired_offer_accounts, close_expired_offer_params);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_expired_offer_ix.clone()],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    reward_center_test::assert_error(tx_response.unwrap_err(), RewardCenterError::OfferNotExpired);
+
+    // CLOSE EXPIRED OFFER TEST
+
+    reward_center_test::warp_clock(&mut context, offer_duration + 1).await;
+
+    let (offer, _) = find_offer_address(buyer_pubkey, &metadata_address, &reward_center);
+
+    let offer_rent = get_account(&mut context.banks_client, offer)
+        .await
+        .unwrap()
+        .lamports;
+    let buyer_lamports = get_account(&mut context.banks_client, *buyer_pubkey)
+        .await
+        .unwrap()
+        .lamports;
+    let cranker_lamports = get_account(&mut context.banks_client, cranker.pubkey())
+        .await
+        .unwrap()
+        .lamports;
+
+    let last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_expired_offer_ix],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    assert!(get_account(&mut context.banks_client, offer)
+        .await
+        .is_none());
+
+    // The escrowed funds and the offer rent go back to the buyer, never to the cranker
+    let offer_price_with_fees =
+        reward_center_test::price_with_fees(reward_center_test::ONE_SOL, 100, 10);
+
+    let buyer_account = get_account(&mut context.banks_client, *buyer_pubkey)
+        .await
+        .unwrap();
+
+    assert!(buyer_account.lamports >= buyer_lamports + offer_price_with_fees + offer_rent);
+
+    let cranker_account = get_account(&mut context.banks_client, cranker.pubkey())
+        .await
+        .unwrap();
+
+    assert!(cranker_account.lamports < cranker_lamports);
+
+    ()
+}
@@ -114,11 +114,7 @@ async fn close_offer_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -222,6 +218,7 @@ async fn close_offer_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -120,11 +120,7 @@ async fn create_listing_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -218,6 +214,7 @@ async fn create_listing_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
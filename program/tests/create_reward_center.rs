@@ -79,11 +79,7 @@ async fn create_reward_center_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
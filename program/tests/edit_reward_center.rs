@@ -2,8 +2,12 @@
 
 pub mod reward_center_test;
 use anchor_client::solana_sdk::{signature::Signer, transaction::Transaction};
+use anchor_lang::{AccountDeserialize, AccountSerialize};
 use mtly_auction_house::pda::find_auction_house_address;
-use mtly_reward_center::{pda::find_reward_center_address, reward_centers, state::*};
+use mtly_reward_center::{
+    errors::RewardCenterError, pda::find_reward_center_address, reward_centers, state::*,
+};
+use reward_center_test::{assert_error, get_account};
 
 use mpl_testing_utils::solana::airdrop;
 use solana_program_test::*;
@@ -79,19 +83,11 @@ async fn edit_reward_center_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let edit_reward_center_params = reward_centers::edit::EditRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Multiple,
-            seller_reward_payout_basis_points: 2000,
-            payout_numeral: 10,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -151,5 +147,149 @@ async fn edit_reward_center_success() {
 
     assert!(tx_response.is_ok());
 
+    // SHRINKING REWARD RULES TEST
+    // Rules going back from vested to immediate payouts leave no stale bytes behind
+
+    let vested_reward_rules = RewardRules {
+        payout_mode: PayoutMode::Vested {
+            cliff: 100,
+            duration: 1_000,
+        },
+        ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            mtly_reward_center_sdk::edit_reward_center(
+                wallet,
+                auction_house,
+                reward_centers::edit::EditRewardCenterParams {
+                    reward_rules: vested_reward_rules,
+                },
+            ),
+            mtly_reward_center_sdk::edit_reward_center(
+                wallet,
+                auction_house,
+                reward_centers::edit::EditRewardCenterParams {
+                    reward_rules: reward_center_test::reward_rules(
+                        PayoutOperation::Multiple,
+                        2000,
+                        10,
+                    ),
+                },
+            ),
+        ],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    let mut serialized_reward_center = Vec::new();
+    reward_center_data
+        .try_serialize(&mut serialized_reward_center)
+        .unwrap();
+
+    assert_eq!(
+        reward_center_data.reward_rules.payout_mode,
+        PayoutMode::Immediate
+    );
+    assert!(reward_center_account.data[serialized_reward_center.len()..]
+        .iter()
+        .all(|byte| *byte == 0));
+
+    // INVALID REWARD RULES TEST
+
+    let invalid_reward_rules = [
+        (
+            reward_center_test::reward_rules(PayoutOperation::Multiple, 10001, 10),
+            RewardCenterError::InvalidSellerRewardPayoutBasisPoints,
+        ),
+        (
+            reward_center_test::reward_rules(PayoutOperation::Divide, 2000, 0),
+            RewardCenterError::InvalidPayoutNumeral,
+        ),
+        (
+            RewardRules {
+                payout_mode: PayoutMode::Vested {
+                    cliff: 100,
+                    duration: 10,
+                },
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidVestingSchedule,
+        ),
+        (
+            RewardRules {
+                attributor_reward_basis_points: 10001,
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidAttributorRewardBasisPoints,
+        ),
+        (
+            RewardRules {
+                maker_taker_split: Some(MakerTakerSplit {
+                    maker_reward_basis_points: 6000,
+                    taker_reward_basis_points: 5000,
+                }),
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidMakerTakerBasisPoints,
+        ),
+        (
+            RewardRules {
+                reward_curve: RewardCurve::Tiered(vec![
+                    reward_center_test::reward_tier(1_000, 1, 1),
+                    reward_center_test::reward_tier(0, 1, 1),
+                ]),
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidRewardCurve,
+        ),
+        (
+            RewardRules {
+                wash_trade_window_seconds: -1,
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidWashTradeWindow,
+        ),
+        (
+            RewardRules {
+                min_order_age_seconds: -1,
+                ..reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10)
+            },
+            RewardCenterError::InvalidMinOrderAge,
+        ),
+    ];
+
+    for (reward_rules, expected_error) in invalid_reward_rules {
+        let edit_reward_center_ix = mtly_reward_center_sdk::edit_reward_center(
+            wallet,
+            auction_house,
+            reward_centers::edit::EditRewardCenterParams { reward_rules },
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[edit_reward_center_ix],
+            Some(&wallet),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let tx_response = context.banks_client.process_transaction(tx).await;
+
+        assert_error(tx_response.unwrap_err(), expected_error);
+    }
+
     ()
 }
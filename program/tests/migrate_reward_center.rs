@@ -0,0 +1,585 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{signature::Signer, transaction::Transaction};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use mtly_auction_house::{
+    pda::{
+        find_auction_house_address, find_auctioneer_trade_state_address, find_trade_state_address,
+    },
+    AuthorityScope,
+};
+use mtly_reward_center::{
+    id,
+    pda::{find_listing_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use reward_center_test::{
+    fixtures::{collection, metadata},
+    get_account,
+};
+
+use mtly_reward_center_sdk::{
+    accounts::{MigrateRewardCenterAccounts, *},
+    args::*,
+    migrate_reward_center, *,
+};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{
+    account::{Account as SolanaAccount, AccountSharedData},
+    program_pack::Pack,
+    signature::Keypair,
+    system_instruction::create_account,
+};
+use std::assert;
+
+use mpl_token_metadata::state::Collection;
+
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::Mint,
+};
+
+#[tokio::test]
+async fn migrate_reward_center_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+    let collection_metadata = collection::create(&mut context).await;
+    let collection = collection_metadata.mint.pubkey();
+
+    let metadata = metadata::create(
+        &mut context,
+        metadata::Params {
+            name: "Test",
+            symbol: "TST",
+            uri: "https://nfts.exp.com/1.json",
+            seller_fee_basis_points: 10,
+            is_mutable: false,
+            collection: Some(Collection {
+                verified: false,
+                key: collection,
+            }),
+            uses: None,
+        },
+        None,
+    )
+    .await;
+
+    collection::verify(&mut context, &metadata, &collection_metadata)
+        .await
+        .unwrap();
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (listing, _) =
+        find_listing_address(&metadata_owner_address, &metadata_address, &reward_center);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let payout_numeral = 5;
+    let seller_reward_payout_basis_points = 1000;
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(
+            PayoutOperation::Multiple,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+        ),
+    };
+
+    let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mtly_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mtly_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = mtly_reward_center_sdk::create_reward_center(
+        mtly_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let add_rewardable_collection_ix = add_rewardable_collection(wallet, auction_house, collection);
+
+    let delegate_auctioneer_accounts = mtly_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mtly_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mtly_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let token_account =
+        get_associated_token_address(&metadata_owner_address, &metadata_mint_address);
+
+    let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+        &metadata_owner_address,
+        &auction_house,
+        &token_account,
+        &mint,
+        &metadata_mint_address,
+        1,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &metadata_owner_address,
+        &auction_house,
+        &token_account,
+        &mint,
+        &metadata_mint_address,
+        0,
+        1,
+    );
+
+    let create_listing_accounts = CreateListingAccounts {
+        wallet: metadata_owner.pubkey(),
+        listing,
+        reward_center,
+        token_account,
+        metadata: metadata.pubkey,
+        authority: wallet,
+        auction_house,
+        seller_trade_state,
+        free_seller_trade_state,
+    };
+
+    let listing_price = reward_center_test::ONE_SOL;
+    let create_listing_params = CreateListingData {
+        price: listing_price,
+        token_size: 1,
+        trade_state_bump,
+        free_trade_state_bump,
+        expires_at: None,
+    };
+
+    let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            add_rewardable_collection_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_listing_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // REWRITE ACCOUNTS WITH THE LEGACY LAYOUT
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    let mut legacy_reward_center_data = RewardCenter::discriminator().to_vec();
+    legacy_reward_center_data.extend_from_slice(reward_mint_pubkey.as_ref());
+    legacy_reward_center_data.extend_from_slice(auction_house.as_ref());
+    legacy_reward_center_data.extend_from_slice(&seller_reward_payout_basis_points.to_le_bytes());
+    legacy_reward_center_data.push(0);
+    legacy_reward_center_data.extend_from_slice(&payout_numeral.to_le_bytes());
+    legacy_reward_center_data.push(reward_center_data.bump);
+    legacy_reward_center_data.resize(110, 0);
+
+    context.set_account(
+        &reward_center,
+        &AccountSharedData::from(SolanaAccount {
+            lamports: rent.minimum_balance(legacy_reward_center_data.len()),
+            data: legacy_reward_center_data,
+            owner: id(),
+            executable: false,
+            rent_epoch: 0,
+        }),
+    );
+
+    let listing_account = get_account(&mut context.banks_client, listing)
+        .await
+        .unwrap();
+    let listing_data = Listing::try_deserialize(&mut listing_account.data.as_slice()).unwrap();
+
+    let mut legacy_listing_data = Listing::discriminator().to_vec();
+    legacy_listing_data.extend_from_slice(reward_center.as_ref());
+    legacy_listing_data.extend_from_slice(metadata_owner_address.as_ref());
+    legacy_listing_data.extend_from_slice(metadata_address.as_ref());
+    legacy_listing_data.extend_from_slice(&listing_price.to_le_bytes());
+    legacy_listing_data.extend_from_slice(&1u64.to_le_bytes());
+    legacy_listing_data.push(listing_data.bump);
+    legacy_listing_data.extend_from_slice(&listing_data.created_at.to_le_bytes());
+
+    context.set_account(
+        &listing,
+        &AccountSharedData::from(SolanaAccount {
+            lamports: rent.minimum_balance(legacy_listing_data.len()),
+            data: legacy_listing_data,
+            owner: id(),
+            executable: false,
+            rent_epoch: 0,
+        }),
+    );
+
+    // MIGRATE REWARD CENTER TEST
+
+    let migrate_reward_center_ix = migrate_reward_center(
+        MigrateRewardCenterAccounts {
+            payer: wallet,
+            auction_house,
+            treasury_mint: mint,
+            rewards_mint: reward_mint_pubkey,
+        },
+        &[listing],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_reward_center_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+
+    assert_eq!(reward_center_account.data.len(), RewardCenter::size());
+    assert!(reward_center_account.lamports >= rent.minimum_balance(RewardCenter::size()));
+
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    assert_eq!(reward_center_data.version, RewardCenter::VERSION);
+    assert_eq!(reward_center_data.token_mint, reward_mint_pubkey);
+    assert_eq!(reward_center_data.auction_house, auction_house);
+    assert_eq!(reward_center_data.authority, wallet);
+    assert_eq!(reward_center_data.pending_authority, None);
+    assert_eq!(reward_center_data.treasury_mint_decimals, 9);
+    assert_eq!(reward_center_data.reward_mint_decimals, 9);
+    assert_eq!(
+        reward_center_data
+            .reward_rules
+            .seller_reward_payout_basis_points,
+        seller_reward_payout_basis_points
+    );
+    assert_eq!(
+        reward_center_data.reward_rules.payout_numeral,
+        payout_numeral
+    );
+    assert_eq!(
+        reward_center_data.reward_rules.payout_mode,
+        PayoutMode::Immediate
+    );
+    assert_eq!(reward_center_data.open_listings, 1);
+    assert!(reward_center_data.has_uncounted_obligations);
+
+    let listing_account = get_account(&mut context.banks_client, listing)
+        .await
+        .unwrap();
+
+    assert_eq!(listing_account.data.len(), Listing::size());
+
+    let listing_data = Listing::try_deserialize(&mut listing_account.data.as_slice()).unwrap();
+
+    assert_eq!(listing_data.version, Listing::VERSION);
+    assert_eq!(listing_data.reward_center, reward_center);
+    assert_eq!(listing_data.seller, metadata_owner_address);
+    assert_eq!(listing_data.price, listing_price);
+    assert_eq!(listing_data.expires_at, None);
+
+    // UPGRADED LISTING STAYS USABLE TEST
+
+    let close_listing_ix = close_listing(
+        CloseListingAccounts {
+            wallet: metadata_owner_address,
+            listing,
+            reward_center,
+            metadata: metadata_address,
+            token_account,
+            authority: wallet,
+            auction_house,
+            token_mint: metadata_mint_address,
+            treasury_mint: mint,
+        },
+        CloseListingData { token_size: 1 },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_listing_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    assert!(get_account(&mut context.banks_client, listing)
+        .await
+        .is_none());
+
+    // UPGRADED REWARD CENTER CAN BE EDITED TEST
+
+    let tiered_reward_rules = RewardRules {
+        reward_curve: RewardCurve::Tiered(vec![
+            reward_center_test::reward_tier(0, 5, 1),
+            reward_center_test::reward_tier(10 * listing_price, 1, 1),
+        ]),
+        ..reward_center_test::reward_rules(
+            PayoutOperation::Multiple,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+        )
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mtly_reward_center_sdk::edit_reward_center(
+            wallet,
+            auction_house,
+            reward_centers::edit::EditRewardCenterParams {
+                reward_rules: tiered_reward_rules.clone(),
+            },
+        )],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    assert_eq!(
+        reward_center_data.reward_rules.reward_curve,
+        tiered_reward_rules.reward_curve
+    );
+
+    // VERSION 2 LISTING UPGRADE TEST
+
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+
+    let create_listing_ix = create_listing(
+        CreateListingAccounts {
+            wallet: metadata_owner_address,
+            listing,
+            reward_center,
+            token_account,
+            metadata: metadata_address,
+            authority: wallet,
+            auction_house,
+            seller_trade_state,
+            free_seller_trade_state,
+        },
+        CreateListingData {
+            price: listing_price,
+            token_size: 1,
+            trade_state_bump,
+            free_trade_state_bump,
+            expires_at: None,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_listing_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let open_listings = get_account(&mut context.banks_client, reward_center)
+        .await
+        .map(|account| {
+            RewardCenter::try_deserialize(&mut account.data.as_slice())
+                .unwrap()
+                .open_listings
+        })
+        .unwrap();
+
+    // Version 2 listings hold whatever was left in the reserved bytes now read as updated_at
+    let mut listing_account = get_account(&mut context.banks_client, listing)
+        .await
+        .unwrap();
+    let mut version_2_listing_data =
+        Listing::try_deserialize(&mut listing_account.data.as_slice()).unwrap();
+
+    version_2_listing_data.version = 2;
+    version_2_listing_data.updated_at = i64::MAX;
+
+    version_2_listing_data
+        .try_serialize(&mut listing_account.data.as_mut_slice())
+        .unwrap();
+
+    context.set_account(&listing, &AccountSharedData::from(listing_account));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_reward_center(
+            MigrateRewardCenterAccounts {
+                payer: wallet,
+                auction_house,
+                treasury_mint: mint,
+                rewards_mint: reward_mint_pubkey,
+            },
+            &[listing],
+        )],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let listing_account = get_account(&mut context.banks_client, listing)
+        .await
+        .unwrap();
+    let listing_data = Listing::try_deserialize(&mut listing_account.data.as_slice()).unwrap();
+
+    assert_eq!(listing_data.version, Listing::VERSION);
+    assert_eq!(listing_data.updated_at, listing_data.created_at);
+    assert_eq!(listing_data.reserved, [0; 24]);
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    assert_eq!(reward_center_data.open_listings, open_listings);
+
+    ()
+}
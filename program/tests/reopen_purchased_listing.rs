@@ -127,11 +127,7 @@ async fn reopen_purchased_listing_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -225,6 +221,7 @@ async fn reopen_purchased_listing_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -306,8 +303,15 @@ async fn reopen_purchased_listing_success() {
 
     let buy_listing_data = BuyListingData {
         price: reward_center_test::ONE_SOL,
+        max_price: reward_center_test::price_with_fees(reward_center_test::ONE_SOL, 100, 10),
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: None,
+        attributor: None,
+        collection_oracle: None,
+        sponsored: false,
+        sale_number: 0,
+        reward_accounts: SaleRewardAccounts::default(),
     };
 
     let accpet_offer_ix = buy_listing(
@@ -317,13 +321,20 @@ async fn reopen_purchased_listing_success() {
     );
 
     let tx = Transaction::new_signed_with_payer(
-        &[
-            create_buyer_reward_token_ix,
-            create_seller_reward_token_ix,
-            accpet_offer_ix,
-        ],
+        &[create_buyer_reward_token_ix, create_seller_reward_token_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accpet_offer_ix],
         Some(&buyer_pubkey),
-        &[&context.payer, &buyer],
+        &[&buyer],
         context.last_blockhash,
     );
 
@@ -362,6 +373,7 @@ async fn reopen_purchased_listing_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let reopen_listing_ix = create_listing(reopen_listing_accounts, reopen_listing_params);
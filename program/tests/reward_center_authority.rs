@@ -0,0 +1,288 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{signature::Signer, transaction::Transaction};
+use anchor_lang::AccountDeserialize;
+use mtly_auction_house::pda::find_auction_house_address;
+use mtly_reward_center::{
+    errors::RewardCenterError, pda::find_reward_center_address, reward_centers, state::*,
+};
+use reward_center_test::{assert_error, get_account};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
+
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::Mint,
+};
+
+#[tokio::test]
+async fn reward_center_authority_transfer_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
+    };
+
+    let edit_reward_center_params = reward_centers::edit::EditRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Multiple, 2000, 10),
+    };
+
+    let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mtly_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mtly_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = mtly_reward_center_sdk::create_reward_center(
+        mtly_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let edit_reward_center_ix = mtly_reward_center_sdk::edit_reward_center(
+        wallet,
+        auction_house,
+        edit_reward_center_params,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            edit_reward_center_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // PROPOSE AUTHORITY TEST
+
+    let new_authority = Keypair::new();
+    let new_authority_pubkey = new_authority.pubkey();
+    airdrop(
+        &mut context,
+        &new_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    let propose_authority_ix =
+        mtly_reward_center_sdk::propose_authority(wallet, auction_house, new_authority_pubkey);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_authority_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    assert_eq!(reward_center_data.authority, wallet);
+    assert_eq!(
+        reward_center_data.pending_authority,
+        Some(new_authority_pubkey)
+    );
+
+    // ACCEPT AUTHORITY WITH WRONG SIGNER TEST
+
+    let impostor = Keypair::new();
+    let impostor_pubkey = impostor.pubkey();
+    airdrop(&mut context, &impostor_pubkey, reward_center_test::TEN_SOL)
+        .await
+        .unwrap();
+
+    let accept_authority_ix =
+        mtly_reward_center_sdk::accept_authority(impostor_pubkey, auction_house);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_authority_ix],
+        Some(&impostor_pubkey),
+        &[&impostor],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert_error(
+        tx_response.unwrap_err(),
+        RewardCenterError::PendingAuthorityMismatch,
+    );
+
+    // ACCEPT AUTHORITY TEST
+
+    let accept_authority_ix =
+        mtly_reward_center_sdk::accept_authority(new_authority_pubkey, auction_house);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[accept_authority_ix],
+        Some(&new_authority_pubkey),
+        &[&new_authority],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut reward_center_account.data.as_slice()).unwrap();
+
+    assert_eq!(reward_center_data.authority, new_authority_pubkey);
+    assert_eq!(reward_center_data.pending_authority, None);
+
+    // PREVIOUS AUTHORITY EDIT TEST
+
+    let edit_reward_center_params = reward_centers::edit::EditRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Multiple, 3000, 10),
+    };
+
+    let edit_reward_center_ix = mtly_reward_center_sdk::edit_reward_center(
+        wallet,
+        auction_house,
+        edit_reward_center_params,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[edit_reward_center_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert_error(
+        tx_response.unwrap_err(),
+        RewardCenterError::SignerNotAuthorized,
+    );
+
+    // NEW AUTHORITY EDIT TEST
+
+    let edit_reward_center_params = reward_centers::edit::EditRewardCenterParams {
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Multiple, 3000, 10),
+    };
+
+    let edit_reward_center_ix = mtly_reward_center_sdk::edit_reward_center(
+        new_authority_pubkey,
+        auction_house,
+        edit_reward_center_params,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[edit_reward_center_ix],
+        Some(&new_authority_pubkey),
+        &[&new_authority],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    ()
+}
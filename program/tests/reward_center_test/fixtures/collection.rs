@@ -0,0 +1,40 @@
+use mpl_testing_utils::{solana::clone_keypair, utils::Metadata};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::signature::Signer;
+
+use crate::reward_center_test::fixtures::metadata;
+
+pub async fn create(context: &mut ProgramTestContext) -> Metadata {
+    metadata::create(
+        context,
+        metadata::Params {
+            name: "Test Collection",
+            symbol: "TST",
+            uri: "https://nfts.exp.com/collection.json",
+            seller_fee_basis_points: 0,
+            is_mutable: true,
+            collection: None,
+            uses: None,
+        },
+        None,
+    )
+    .await
+}
+
+pub async fn verify(
+    context: &mut ProgramTestContext,
+    item: &Metadata,
+    collection: &Metadata,
+) -> Result<(), BanksClientError> {
+    let collection_authority = clone_keypair(&context.payer);
+
+    item.verify_collection(
+        context,
+        collection.pubkey,
+        collection_authority,
+        collection.mint.pubkey(),
+        collection.master_edition,
+        None,
+    )
+    .await
+}
@@ -1,7 +1,14 @@
 pub mod fixtures;
 
+use mtly_reward_center::{
+    errors::RewardCenterError,
+    state::{PayoutMode, PayoutOperation, RewardCurve, RewardRules, RewardTier},
+};
 use solana_program_test::*;
-use solana_sdk::{account::Account, pubkey::Pubkey};
+use solana_sdk::{
+    account::Account, clock::Clock, instruction::InstructionError, pubkey::Pubkey,
+    transaction::TransactionError,
+};
 
 pub const TEN_SOL: u64 = 10_000_000_000;
 pub const ONE_SOL: u64 = 1_000_000_000;
@@ -17,6 +24,68 @@ pub fn setup_program<'a>() -> ProgramTest {
     program
 }
 
+pub fn price_with_fees(price: u64, auction_house_fee_bps: u16, seller_fee_bps: u16) -> u64 {
+    price
+        + ((auction_house_fee_bps as u64 * price) / 10000)
+        + ((seller_fee_bps as u64 * price) / 10000)
+}
+
 pub async fn get_account(bank_client: &mut BanksClient, address: Pubkey) -> Option<Account> {
     bank_client.get_account(address).await.unwrap()
 }
+
+pub async fn get_clock(context: &mut ProgramTestContext) -> Clock {
+    context.banks_client.get_sysvar::<Clock>().await.unwrap()
+}
+
+pub async fn warp_clock(context: &mut ProgramTestContext, seconds: i64) {
+    let mut clock = get_clock(context).await;
+    clock.unix_timestamp += seconds;
+    context.set_sysvar(&clock);
+}
+
+pub async fn warp_epoch(context: &mut ProgramTestContext) {
+    let mut clock = get_clock(context).await;
+    clock.epoch += 1;
+    context.set_sysvar(&clock);
+}
+
+pub fn assert_error(error: BanksClientError, expected_error: RewardCenterError) {
+    match error {
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(code, u32::from(expected_error)),
+        _ => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+/// Reward rules paying through the given ratio, every other rule left at its default.
+pub fn reward_rules(
+    mathematical_operand: PayoutOperation,
+    seller_reward_payout_basis_points: u16,
+    payout_numeral: u16,
+) -> RewardRules {
+    RewardRules {
+        seller_reward_payout_basis_points,
+        mathematical_operand,
+        payout_numeral,
+        payout_mode: PayoutMode::Immediate,
+        attributor_reward_basis_points: 0,
+        collection_oracle: None,
+        maker_taker_split: None,
+        reward_curve: RewardCurve::Linear,
+        wash_trade_window_seconds: 0,
+        min_order_age_seconds: 0,
+        max_reward_per_sale: None,
+        max_reward_per_wallet_per_epoch: None,
+    }
+}
+
+pub fn reward_tier(price_threshold: u64, numerator: u16, denominator: u16) -> RewardTier {
+    RewardTier {
+        price_threshold,
+        numerator,
+        denominator,
+    }
+}
@@ -3,28 +3,34 @@
 pub mod reward_center_test;
 
 use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use anchor_lang::{AccountDeserialize, AccountSerialize};
 use mtly_auction_house::{
     pda::{
-        find_auction_house_address, find_auctioneer_trade_state_address, find_trade_state_address,
+        find_auction_house_address, find_auctioneer_trade_state_address,
+        find_escrow_payment_address, find_trade_state_address,
     },
     AuthorityScope,
 };
 use mtly_reward_center::{
-    pda::{find_listing_address, find_reward_center_address},
+    errors::RewardCenterError,
+    pda::{find_listing_address, find_offer_address, find_reward_center_address},
     reward_centers,
     state::*,
 };
-use reward_center_test::fixtures::metadata;
+use reward_center_test::{assert_error, fixtures::metadata, get_account};
 
 use mtly_reward_center_sdk::{
-    accounts::{CloseOfferAccounts, CreateListingAccounts, CreateOfferAccounts},
-    args::{CloseOfferData, CreateListingData, CreateOfferData},
+    accounts::{CreateListingAccounts, CreateOfferAccounts, UpdateOfferAccounts},
+    args::{CreateListingData, CreateOfferData, UpdateOfferData},
     *,
 };
 
 use mpl_testing_utils::solana::airdrop;
 use solana_program_test::*;
-use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
+use solana_sdk::{
+    account::AccountSharedData, program_pack::Pack, signature::Keypair,
+    system_instruction::create_account,
+};
 use std::str::FromStr;
 
 use mpl_token_metadata::state::Collection;
@@ -37,7 +43,7 @@ use spl_token::{
 };
 
 #[tokio::test]
-async fn create_offer_success() {
+async fn update_offer_success() {
     let program = reward_center_test::setup_program();
     let mut context = program.start_with_context().await;
     let rent = context.banks_client.get_rent().await.unwrap();
@@ -125,11 +131,7 @@ async fn create_offer_success() {
     .unwrap();
 
     let reward_center_params = reward_centers::create::CreateRewardCenterParams {
-        reward_rules: RewardRules {
-            mathematical_operand: PayoutOperation::Divide,
-            seller_reward_payout_basis_points: 1000,
-            payout_numeral: 5,
-        },
+        reward_rules: reward_center_test::reward_rules(PayoutOperation::Divide, 1000, 5),
     };
 
     let create_auction_house_accounts = mtly_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -223,6 +225,7 @@ async fn create_offer_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -284,6 +287,7 @@ async fn create_offer_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -299,47 +303,102 @@ async fn create_offer_success() {
 
     assert!(tx_response.is_ok());
 
-    let cancel_offer_accounts = CloseOfferAccounts {
+    let (offer, _) = find_offer_address(buyer_pubkey, &metadata_address, &reward_center);
+    let (escrow_payment_account, _) = find_escrow_payment_address(&auction_house, buyer_pubkey);
+
+    let offer_account = get_account(&mut context.banks_client, offer).await.unwrap();
+    let created_offer = Offer::try_deserialize(&mut offer_account.data.as_slice()).unwrap();
+
+    let escrow_lamports = get_account(&mut context.banks_client, escrow_payment_account)
+        .await
+        .unwrap()
+        .lamports;
+
+    // UPDATE OFFER TO A HIGHER PRICE TEST
+
+    let raised_price = reward_center_test::ONE_SOL * 2;
+
+    let update_offer_accounts = UpdateOfferAccounts {
         wallet: *buyer_pubkey,
+        transfer_authority: *buyer_pubkey,
+        payment_account: *buyer_pubkey,
+        receipt_account: *buyer_pubkey,
         treasury_mint: mint,
         token_mint: metadata_mint_address,
         token_account,
-        receipt_account: *buyer_pubkey,
         metadata: metadata_address,
         authority: wallet,
-        auction_house,
         reward_center,
+        auction_house,
     };
 
-    let cancel_offer_params = CloseOfferData {
+    let update_offer_params = UpdateOfferData {
+        old_price: reward_center_test::ONE_SOL,
+        new_price: raised_price,
         token_size: 1,
-        buyer_price: reward_center_test::ONE_SOL,
     };
 
-    let cancel_offer_ix = close_offer(cancel_offer_accounts, cancel_offer_params);
+    let update_offer_ix = update_offer(update_offer_accounts, update_offer_params);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_offer_ix],
+        Some(buyer_pubkey),
+        &[&buyer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let offer_account = get_account(&mut context.banks_client, offer).await.unwrap();
+    let raised_offer = Offer::try_deserialize(&mut offer_account.data.as_slice()).unwrap();
+
+    assert_eq!(raised_offer.price, raised_price);
+    assert_eq!(
+        raised_offer.price_with_fees,
+        reward_center_test::price_with_fees(raised_price, 100, 10)
+    );
+    assert_eq!(raised_offer.created_at, created_offer.created_at);
 
-    let update_offer_accounts = CreateOfferAccounts {
+    let raised_escrow_lamports = get_account(&mut context.banks_client, escrow_payment_account)
+        .await
+        .unwrap()
+        .lamports;
+
+    assert_eq!(
+        raised_escrow_lamports - escrow_lamports,
+        raised_offer.price_with_fees - created_offer.price_with_fees
+    );
+
+    // UPDATE OFFER TO A LOWER PRICE TEST
+
+    let lowered_price = reward_center_test::ONE_SOL / 2;
+
+    let update_offer_accounts = UpdateOfferAccounts {
         wallet: *buyer_pubkey,
         transfer_authority: *buyer_pubkey,
         payment_account: *buyer_pubkey,
+        receipt_account: *buyer_pubkey,
         treasury_mint: mint,
         token_mint: metadata_mint_address,
-        auction_house,
-        reward_center,
         token_account,
         metadata: metadata_address,
         authority: wallet,
+        reward_center,
+        auction_house,
     };
 
-    let update_offer_params = CreateOfferData {
+    let update_offer_params = UpdateOfferData {
+        old_price: raised_price,
+        new_price: lowered_price,
         token_size: 1,
-        buyer_price: reward_center_test::ONE_SOL * 2,
     };
 
-    let update_offer_ix = create_offer(update_offer_accounts, update_offer_params);
+    let update_offer_ix = update_offer(update_offer_accounts, update_offer_params);
 
     let tx = Transaction::new_signed_with_payer(
-        &[cancel_offer_ix],
+        &[update_offer_ix],
         Some(buyer_pubkey),
         &[&buyer],
         context.last_blockhash,
@@ -349,6 +408,65 @@ async fn create_offer_success() {
 
     assert!(tx_response.is_ok());
 
+    let offer_account = get_account(&mut context.banks_client, offer).await.unwrap();
+    let lowered_offer = Offer::try_deserialize(&mut offer_account.data.as_slice()).unwrap();
+
+    assert_eq!(lowered_offer.price, lowered_price);
+    assert_eq!(
+        lowered_offer.price_with_fees,
+        reward_center_test::price_with_fees(lowered_price, 100, 10)
+    );
+
+    let lowered_escrow_lamports = get_account(&mut context.banks_client, escrow_payment_account)
+        .await
+        .unwrap()
+        .lamports;
+
+    assert_eq!(
+        raised_escrow_lamports - lowered_escrow_lamports,
+        raised_offer.price_with_fees - lowered_offer.price_with_fees
+    );
+
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+
+    // UPDATE OFFER WITHOUT A KNOWN ESCROW TEST
+
+    let mut legacy_offer = lowered_offer;
+    legacy_offer.price_with_fees = 0;
+
+    let mut legacy_offer_account = offer_account.clone();
+    legacy_offer
+        .try_serialize(&mut legacy_offer_account.data.as_mut_slice())
+        .unwrap();
+
+    context.set_account(&offer, &AccountSharedData::from(legacy_offer_account));
+
+    let update_offer_accounts = UpdateOfferAccounts {
+        wallet: *buyer_pubkey,
+        transfer_authority: *buyer_pubkey,
+        payment_account: *buyer_pubkey,
+        receipt_account: *buyer_pubkey,
+        treasury_mint: mint,
+        token_mint: metadata_mint_address,
+        token_account,
+        metadata: metadata_address,
+        authority: wallet,
+        reward_center,
+        auction_house,
+    };
+
+    let update_offer_params = UpdateOfferData {
+        old_price: lowered_price,
+        new_price: reward_center_test::ONE_SOL / 4,
+        token_size: 1,
+    };
+
+    let update_offer_ix = update_offer(update_offer_accounts, update_offer_params);
+
     let tx = Transaction::new_signed_with_payer(
         &[update_offer_ix],
         Some(buyer_pubkey),
@@ -358,7 +476,10 @@ async fn create_offer_success() {
 
     let tx_response = context.banks_client.process_transaction(tx).await;
 
-    assert!(tx_response.is_ok());
+    assert_error(
+        tx_response.unwrap_err(),
+        RewardCenterError::OfferEscrowUnknown,
+    );
 
     ()
 }
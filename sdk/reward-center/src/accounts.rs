@@ -12,6 +12,47 @@ pub struct WithdrawRewardCenterFundsAccounts {
     pub auction_house: Pubkey,
 }
 
+pub struct CreateSponsorPoolAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub collection_mint: Pubkey,
+    pub collection_metadata: Pubkey,
+    pub rewards_mint: Pubkey,
+}
+
+pub struct SponsorPoolFundsAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub collection_mint: Pubkey,
+    pub rewards_mint: Pubkey,
+}
+
+pub struct CloseRewardCenterAccounts {
+    pub wallet: Pubkey,
+    pub rewards_mint: Pubkey,
+    pub auction_house: Pubkey,
+    pub destination_reward_token_account: Pubkey,
+}
+
+pub struct MigrateRewardCenterAccounts {
+    pub payer: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub rewards_mint: Pubkey,
+}
+
+pub struct ClaimRewardDebtAccounts {
+    pub wallet: Pubkey,
+    pub rewards_mint: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct ClaimVestedRewardsAccounts {
+    pub wallet: Pubkey,
+    pub rewards_mint: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct CreateListingAccounts {
     pub wallet: Pubkey,
     pub listing: Pubkey,
@@ -36,6 +77,19 @@ pub struct CloseListingAccounts {
     pub treasury_mint: Pubkey,
 }
 
+pub struct CloseExpiredListingAccounts {
+    pub payer: Pubkey,
+    pub seller: Pubkey,
+    pub listing: Pubkey,
+    pub reward_center: Pubkey,
+    pub metadata: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+}
+
 pub struct UpdateListingAccounts {
     pub wallet: Pubkey,
     pub metadata: Pubkey,
@@ -56,6 +110,20 @@ pub struct CreateOfferAccounts {
     pub auction_house: Pubkey,
 }
 
+pub struct UpdateOfferAccounts {
+    pub wallet: Pubkey,
+    pub transfer_authority: Pubkey,
+    pub payment_account: Pubkey,
+    pub receipt_account: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub authority: Pubkey,
+    pub reward_center: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct CloseOfferAccounts {
     pub wallet: Pubkey,
     pub receipt_account: Pubkey,
@@ -68,6 +136,19 @@ pub struct CloseOfferAccounts {
     pub auction_house: Pubkey,
 }
 
+pub struct CloseExpiredOfferAccounts {
+    pub payer: Pubkey,
+    pub buyer: Pubkey,
+    pub receipt_account: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub authority: Pubkey,
+    pub reward_center: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct BuyListingAccounts {
     pub buyer: Pubkey,
     pub transfer_authority: Pubkey,
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::Pubkey;
+use mtly_reward_center::state::{PayoutMode, RewardRules};
 
 pub struct CreateListingData {
     pub price: u64,
     pub token_size: u64,
     pub trade_state_bump: u8,
     pub free_trade_state_bump: u8,
+    pub expires_at: Option<i64>,
 }
 
 pub struct UpdateListingData {
@@ -18,6 +20,13 @@ pub struct CloseListingData {
 pub struct CreateOfferData {
     pub buyer_price: u64,
     pub token_size: u64,
+    pub expires_at: Option<i64>,
+}
+
+pub struct UpdateOfferData {
+    pub old_price: u64,
+    pub new_price: u64,
+    pub token_size: u64,
 }
 
 pub struct CloseOfferData {
@@ -27,12 +36,61 @@ pub struct CloseOfferData {
 
 pub struct BuyListingData {
     pub price: u64,
+    /// highest listing price the buyer accepts, before fees
+    pub max_price: u64,
     pub token_size: u64,
     pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub attributor: Option<Pubkey>,
+    pub collection_oracle: Option<Pubkey>,
+    pub sponsored: bool,
+    /// sales of the NFT so far, as returned by `fetch_sale_count` or `read_sale_count`, numbering
+    /// the purchase ticket of the sale; the sale fails when it is out of date
+    pub sale_number: u64,
+    pub reward_accounts: SaleRewardAccounts,
 }
 
 pub struct AcceptOfferData {
     pub price: u64,
+    pub min_price: u64,
     pub token_size: u64,
     pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub attributor: Option<Pubkey>,
+    pub collection_oracle: Option<Pubkey>,
+    pub sponsored: bool,
+    /// sales of the NFT so far, as returned by `fetch_sale_count` or `read_sale_count`, numbering
+    /// the purchase ticket of the sale; the sale fails when it is out of date
+    pub sale_number: u64,
+    pub reward_accounts: SaleRewardAccounts,
+}
+
+/// Reward accounts passed along with a sale, which are only needed under the reward rules using
+/// them
+#[derive(Clone, Copy, Default)]
+pub struct SaleRewardAccounts {
+    /// reward debt accounts of the buyer and the seller, needed in immediate payout mode when the
+    /// reward treasury cannot cover the rewards of the sale
+    pub reward_debt: bool,
+    /// reward vesting accounts of the buyer and the seller, needed in vested payout mode
+    pub reward_vesting: bool,
+    /// sale record of the NFT, needed while the wash trade window is set
+    pub sale_record: bool,
+    /// wallet reward windows of the buyer and the seller, needed while rewards are capped per
+    /// wallet
+    pub reward_window: bool,
+}
+
+impl SaleRewardAccounts {
+    /// The reward accounts needed by the reward rules, with the reward debt accounts only passed
+    /// when the reward treasury may be short
+    pub fn for_rules(reward_rules: &RewardRules, treasury_short: bool) -> Self {
+        Self {
+            reward_debt: treasury_short
+                && matches!(reward_rules.payout_mode, PayoutMode::Immediate),
+            reward_vesting: matches!(reward_rules.payout_mode, PayoutMode::Vested { .. }),
+            sale_record: reward_rules.wash_trade_window_seconds > 0,
+            reward_window: reward_rules.max_reward_per_wallet_per_epoch.is_some(),
+        }
+    }
 }
@@ -3,7 +3,15 @@ pub mod args;
 
 pub use accounts::*;
 
-use anchor_client::solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program, sysvar};
+use std::ops::Deref;
+
+use anchor_client::{
+    solana_sdk::{
+        instruction::Instruction, pubkey::Pubkey, signer::Signer as SolanaSigner, system_program,
+        sysvar,
+    },
+    ClientError, Program,
+};
 use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta, InstructionData};
 use args::*;
 use mtly_auction_house::pda::{
@@ -11,11 +19,30 @@ use mtly_auction_house::pda::{
     find_public_bid_trade_state_address, find_trade_state_address,
 };
 use mtly_reward_center::{
-    accounts as rewards_accounts, id, instruction,
+    accounts as rewards_accounts,
+    attribution::attribute::AttributeParams,
+    id, instruction,
     listings::{buy::BuyListingParams, create::CreateListingParams, update::UpdateListingParams},
-    offers::{accept::AcceptOfferParams, close::CloseOfferParams, create::CreateOfferParams},
-    pda::{self, find_listing_address, find_offer_address, find_reward_center_address},
-    reward_centers::{create::CreateRewardCenterParams, edit::EditRewardCenterParams},
+    offers::{
+        accept::AcceptOfferParams, close::CloseOfferParams, create::CreateOfferParams,
+        update::UpdateOfferParams,
+    },
+    pda::{
+        self, find_attributor_address, find_listing_address, find_offer_address,
+        find_purchase_ticket_address, find_reward_center_address, find_reward_debt_address,
+        find_reward_vesting_address, find_rewardable_collection_address, find_sale_counter_address,
+        find_sale_record_address, find_sponsor_pool_address, find_wallet_reward_window_address,
+    },
+    reward_centers::{
+        close::CloseRewardCenterParams, create::CreateRewardCenterParams,
+        edit::EditRewardCenterParams, propose_authority::ProposeAuthorityParams,
+        set_pause_state::SetPauseStateParams, set_reward_mode::SetRewardModeParams,
+    },
+    sponsor_pools::{
+        create::CreateSponsorPoolParams, deposit::DepositSponsorPoolFundsParams,
+        withdraw::WithdrawSponsorPoolFundsParams,
+    },
+    state::{PurchaseTicket, RewardMode, RewardRules, SaleCounter},
     withdraw::reward_center::WithdrawRewardCenterFundsParams,
 };
 use spl_associated_token_account::get_associated_token_address;
@@ -58,24 +85,537 @@ pub fn create_reward_center(
     }
 }
 
-pub fn edit_reward_center(
+pub fn edit_reward_center(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    edit_reward_center_params: EditRewardCenterParams,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::EditRewardCenter {
+        wallet,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::EditRewardCenter {
+        edit_reward_center_params,
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn propose_authority(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    new_authority: Pubkey,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::ProposeAuthority {
+        wallet,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ProposeAuthority {
+        propose_authority_params: ProposeAuthorityParams { new_authority },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn accept_authority(new_authority: Pubkey, auction_house: Pubkey) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::AcceptAuthority {
+        new_authority,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AcceptAuthority {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn set_pause_state(wallet: Pubkey, auction_house: Pubkey, pause_state: u8) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::SetPauseState {
+        wallet,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::SetPauseState {
+        set_pause_state_params: SetPauseStateParams { pause_state },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn set_reward_mode(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    reward_mode: RewardMode,
+    candidate_reward_rules: Option<RewardRules>,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::SetRewardMode {
+        wallet,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::SetRewardMode {
+        set_reward_mode_params: SetRewardModeParams {
+            reward_mode,
+            candidate_reward_rules,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn withdraw_reward_center_funds(
+    WithdrawRewardCenterFundsAccounts {
+        wallet,
+        auction_house,
+        rewards_mint,
+    }: WithdrawRewardCenterFundsAccounts,
+    withdrawal_amount: u64,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &rewards_mint);
+
+    let destination_reward_token_account = get_associated_token_address(&wallet, &rewards_mint);
+
+    let accounts = rewards_accounts::WithdrawRewardCenterFunds {
+        wallet,
+        auction_house,
+        reward_center,
+        reward_center_reward_token_account,
+        destination_reward_token_account,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::WithdrawRewardCenterFunds {
+        withdraw_reward_center_funds_params: WithdrawRewardCenterFundsParams { withdrawal_amount },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_sponsor_pool(
+    CreateSponsorPoolAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+        collection_metadata,
+        rewards_mint,
+    }: CreateSponsorPoolAccounts,
+    create_sponsor_pool_params: CreateSponsorPoolParams,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (sponsor_pool, _) = find_sponsor_pool_address(&reward_center, &collection_mint);
+    let sponsor_pool_reward_token_account =
+        get_associated_token_address(&sponsor_pool, &rewards_mint);
+
+    let accounts = rewards_accounts::CreateSponsorPool {
+        wallet,
+        collection_mint,
+        collection_metadata,
+        reward_mint: rewards_mint,
+        sponsor_pool,
+        sponsor_pool_reward_token_account,
+        auction_house,
+        reward_center,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateSponsorPool {
+        create_sponsor_pool_params,
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn deposit_sponsor_pool_funds(
+    SponsorPoolFundsAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+        rewards_mint,
+    }: SponsorPoolFundsAccounts,
+    deposit_amount: u64,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (sponsor_pool, _) = find_sponsor_pool_address(&reward_center, &collection_mint);
+
+    let accounts = rewards_accounts::DepositSponsorPoolFunds {
+        wallet,
+        source_reward_token_account: get_associated_token_address(&wallet, &rewards_mint),
+        sponsor_pool_reward_token_account: get_associated_token_address(
+            &sponsor_pool,
+            &rewards_mint,
+        ),
+        sponsor_pool,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::DepositSponsorPoolFunds {
+        deposit_sponsor_pool_funds_params: DepositSponsorPoolFundsParams { deposit_amount },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn withdraw_sponsor_pool_funds(
+    SponsorPoolFundsAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+        rewards_mint,
+    }: SponsorPoolFundsAccounts,
+    withdrawal_amount: u64,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (sponsor_pool, _) = find_sponsor_pool_address(&reward_center, &collection_mint);
+
+    let accounts = rewards_accounts::WithdrawSponsorPoolFunds {
+        wallet,
+        sponsor_pool_reward_token_account: get_associated_token_address(
+            &sponsor_pool,
+            &rewards_mint,
+        ),
+        destination_reward_token_account: get_associated_token_address(&wallet, &rewards_mint),
+        sponsor_pool,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::WithdrawSponsorPoolFunds {
+        withdraw_sponsor_pool_funds_params: WithdrawSponsorPoolFundsParams { withdrawal_amount },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn close_reward_center(
+    CloseRewardCenterAccounts {
+        wallet,
+        auction_house,
+        rewards_mint,
+        destination_reward_token_account,
+    }: CloseRewardCenterAccounts,
+    force: bool,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &rewards_mint);
+
+    let accounts = rewards_accounts::CloseRewardCenter {
+        wallet,
+        reward_center_reward_token_account,
+        destination_reward_token_account,
+        reward_center,
+        auction_house,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseRewardCenter {
+        close_reward_center_params: CloseRewardCenterParams { force },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn migrate_reward_center(
+    MigrateRewardCenterAccounts {
+        payer,
+        auction_house,
+        treasury_mint,
+        rewards_mint,
+    }: MigrateRewardCenterAccounts,
+    listings_and_offers: &[Pubkey],
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+
+    let mut accounts = rewards_accounts::MigrateRewardCenter {
+        payer,
+        reward_center,
+        treasury_mint,
+        reward_mint: rewards_mint,
+        auction_house,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    accounts.extend(
+        listings_and_offers
+            .iter()
+            .map(|account| AccountMeta::new(*account, false)),
+    );
+
+    let data = instruction::MigrateRewardCenter {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn close_purchase_ticket(payer: Pubkey, purchase_ticket: Pubkey) -> Instruction {
+    let accounts = rewards_accounts::ClosePurchaseTicket {
+        payer,
+        purchase_ticket,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ClosePurchaseTicket {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn claim_reward_debt(
+    ClaimRewardDebtAccounts {
+        wallet,
+        auction_house,
+        rewards_mint,
+    }: ClaimRewardDebtAccounts,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (reward_debt, _) = find_reward_debt_address(&reward_center, &wallet);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &rewards_mint);
+
+    let destination_reward_token_account = get_associated_token_address(&wallet, &rewards_mint);
+
+    let accounts = rewards_accounts::ClaimRewardDebt {
+        wallet,
+        reward_debt,
+        destination_reward_token_account,
+        reward_center_reward_token_account,
+        reward_center,
+        auction_house,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ClaimRewardDebt {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn claim_vested_rewards(
+    ClaimVestedRewardsAccounts {
+        wallet,
+        auction_house,
+        rewards_mint,
+    }: ClaimVestedRewardsAccounts,
+) -> Instruction {
+    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (reward_vesting, _) = find_reward_vesting_address(&reward_center, &wallet);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &rewards_mint);
+
+    let destination_reward_token_account = get_associated_token_address(&wallet, &rewards_mint);
+
+    let accounts = rewards_accounts::ClaimVestedRewards {
+        wallet,
+        reward_vesting,
+        destination_reward_token_account,
+        reward_center_reward_token_account,
+        reward_center,
+        auction_house,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ClaimVestedRewards {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn add_rewardable_collection(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    collection_mint: Pubkey,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (rewardable_collection, _) =
+        find_rewardable_collection_address(&reward_center, &collection_mint);
+
+    let accounts = rewards_accounts::AddRewardableCollection {
+        wallet,
+        collection_mint,
+        rewardable_collection,
+        auction_house,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AddRewardableCollection {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn remove_rewardable_collection(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    collection_mint: Pubkey,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (rewardable_collection, _) =
+        find_rewardable_collection_address(&reward_center, &collection_mint);
+
+    let accounts = rewards_accounts::RemoveRewardableCollection {
+        wallet,
+        rewardable_collection,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::RemoveRewardableCollection {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn add_attributor(
+    wallet: Pubkey,
+    auction_house: Pubkey,
+    attributor_wallet: Pubkey,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (attributor, _) = find_attributor_address(&reward_center, &attributor_wallet);
+
+    let accounts = rewards_accounts::AddAttributor {
+        wallet,
+        attributor_wallet,
+        attributor,
+        auction_house,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AddAttributor {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn remove_attributor(
     wallet: Pubkey,
     auction_house: Pubkey,
-    edit_reward_center_params: EditRewardCenterParams,
+    attributor_wallet: Pubkey,
 ) -> Instruction {
-    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (attributor, _) = find_attributor_address(&reward_center, &attributor_wallet);
 
-    let accounts = rewards_accounts::EditRewardCenter {
+    let accounts = rewards_accounts::RemoveAttributor {
         wallet,
+        attributor,
         auction_house,
         reward_center,
     }
     .to_account_metas(None);
 
-    let data = instruction::EditRewardCenter {
-        edit_reward_center_params,
-    }
-    .data();
+    let data = instruction::RemoveAttributor {}.data();
 
     Instruction {
         program_id: id(),
@@ -84,33 +624,18 @@ pub fn edit_reward_center(
     }
 }
 
-pub fn withdraw_reward_center_funds(
-    WithdrawRewardCenterFundsAccounts {
-        wallet,
-        auction_house,
-        rewards_mint,
-    }: WithdrawRewardCenterFundsAccounts,
-    withdrawal_amount: u64,
-) -> Instruction {
-    let (reward_center, _) = pda::find_reward_center_address(&auction_house);
-
-    let reward_center_reward_token_account =
-        get_associated_token_address(&reward_center, &rewards_mint);
-
-    let destination_reward_token_account = get_associated_token_address(&wallet, &rewards_mint);
+pub fn attribute(auction_house: Pubkey, attributor_wallet: Pubkey, memo: String) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (attributor, _) = find_attributor_address(&reward_center, &attributor_wallet);
 
-    let accounts = rewards_accounts::WithdrawRewardCenterFunds {
-        wallet,
-        auction_house,
-        reward_center,
-        reward_center_reward_token_account,
-        destination_reward_token_account,
-        token_program: spl_token::id(),
+    let accounts = rewards_accounts::Attribute {
+        wallet: attributor_wallet,
+        attributor,
     }
     .to_account_metas(None);
 
-    let data = instruction::WithdrawRewardCenterFunds {
-        withdraw_reward_center_funds_params: WithdrawRewardCenterFundsParams { withdrawal_amount },
+    let data = instruction::Attribute {
+        attribute_params: AttributeParams { memo },
     }
     .data();
 
@@ -138,6 +663,7 @@ pub fn create_listing(
         token_size,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at,
     }: CreateListingData,
 ) -> Instruction {
     let (auction_house_fee_account, _) =
@@ -174,6 +700,7 @@ pub fn create_listing(
             trade_state_bump,
             free_trade_state_bump,
             program_as_signer_bump,
+            expires_at,
         },
     }
     .data();
@@ -239,6 +766,62 @@ pub fn close_listing(
     }
 }
 
+pub fn close_expired_listing(
+    CloseExpiredListingAccounts {
+        payer,
+        seller,
+        listing,
+        reward_center,
+        authority,
+        metadata,
+        token_account,
+        token_mint,
+        treasury_mint,
+        auction_house,
+    }: CloseExpiredListingAccounts,
+    CloseListingData { token_size }: CloseListingData,
+) -> Instruction {
+    let (auction_house_fee_account, _) =
+        mtly_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mtly_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+
+    let (seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let accounts = rewards_accounts::CloseExpiredListing {
+        payer,
+        seller,
+        ah_auctioneer_pda,
+        auction_house,
+        auction_house_fee_account,
+        authority,
+        listing,
+        metadata,
+        reward_center,
+        token_account,
+        token_mint,
+        trade_state: seller_trade_state,
+        auction_house_program: mtly_auction_house::id(),
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseExpiredListing {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
 pub fn update_listing(
     UpdateListingAccounts {
         auction_house,
@@ -290,6 +873,7 @@ pub fn create_offer(
     CreateOfferData {
         buyer_price,
         token_size,
+        expires_at,
     }: CreateOfferData,
 ) -> Instruction {
     let (auction_house_fee_account, _) =
@@ -339,6 +923,96 @@ pub fn create_offer(
             escrow_payment_bump,
             token_size,
             trade_state_bump,
+            expires_at,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn update_offer(
+    UpdateOfferAccounts {
+        wallet,
+        transfer_authority,
+        payment_account,
+        receipt_account,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        authority,
+        reward_center,
+        auction_house,
+    }: UpdateOfferAccounts,
+    UpdateOfferData {
+        old_price,
+        new_price,
+        token_size,
+    }: UpdateOfferData,
+) -> Instruction {
+    let (auction_house_fee_account, _) =
+        mtly_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mtly_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mtly_auction_house::pda::find_escrow_payment_address(&auction_house, &wallet);
+
+    let (old_buyer_trade_state, _) = find_public_bid_trade_state_address(
+        &wallet,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        old_price,
+        token_size,
+    );
+
+    let (buyer_trade_state, trade_state_bump) = find_public_bid_trade_state_address(
+        &wallet,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        new_price,
+        token_size,
+    );
+
+    let (offer, _) = pda::find_offer_address(&wallet, &metadata, &reward_center);
+
+    let accounts = rewards_accounts::UpdateOffer {
+        wallet,
+        offer,
+        payment_account,
+        transfer_authority,
+        receipt_account,
+        treasury_mint,
+        token_account,
+        token_mint,
+        metadata,
+        escrow_payment_account,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        old_buyer_trade_state,
+        buyer_trade_state,
+        ah_auctioneer_pda,
+        auction_house_program: mtly_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::UpdateOffer {
+        update_offer_params: UpdateOfferParams {
+            trade_state_bump,
+            escrow_payment_bump,
+            new_price,
         },
     }
     .data();
@@ -422,6 +1096,80 @@ pub fn close_offer(
     }
 }
 
+pub fn close_expired_offer(
+    CloseExpiredOfferAccounts {
+        payer,
+        buyer,
+        auction_house,
+        authority,
+        metadata,
+        receipt_account,
+        reward_center,
+        token_account,
+        token_mint,
+        treasury_mint,
+    }: CloseExpiredOfferAccounts,
+    CloseOfferData {
+        buyer_price,
+        token_size,
+    }: CloseOfferData,
+) -> Instruction {
+    let (auction_house_fee_account, _) =
+        mtly_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mtly_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mtly_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
+
+    let (buyer_trade_state, _trade_state_bump) = find_public_bid_trade_state_address(
+        &buyer,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        buyer_price,
+        token_size,
+    );
+
+    let (offer, _) = pda::find_offer_address(&buyer, &metadata, &reward_center);
+
+    let accounts = rewards_accounts::CloseExpiredOffer {
+        payer,
+        buyer,
+        ah_auctioneer_pda,
+        auction_house,
+        auction_house_fee_account,
+        authority,
+        escrow_payment_account,
+        metadata,
+        offer,
+        receipt_account,
+        reward_center,
+        token_account,
+        token_mint,
+        trade_state: buyer_trade_state,
+        treasury_mint,
+        auction_house_program: mtly_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseExpiredOffer {
+        close_offer_params: CloseOfferParams {
+            escrow_payment_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
 pub fn buy_listing(
     BuyListingAccounts {
         transfer_authority,
@@ -440,12 +1188,20 @@ pub fn buy_listing(
     BuyListingData {
         token_size,
         price,
+        max_price,
         reward_mint,
+        collection,
+        attributor,
+        collection_oracle,
+        sponsored,
+        sale_number,
+        reward_accounts,
     }: BuyListingData,
     creators: Vec<AccountMeta>,
 ) -> Instruction {
     let (reward_center, _) = find_reward_center_address(&auction_house);
     let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+    let (_, offer_bump) = find_offer_address(&buyer, &metadata, &reward_center);
 
     let (auction_house_fee_account, _) =
         mtly_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
@@ -459,6 +1215,40 @@ pub fn buy_listing(
         get_associated_token_address(&reward_center, &reward_mint);
     let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
     let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+    let (rewardable_collection, _) =
+        find_rewardable_collection_address(&reward_center, &collection.unwrap_or_default());
+    let buyer_reward_debt = reward_accounts
+        .reward_debt
+        .then(|| find_reward_debt_address(&reward_center, &buyer).0);
+    let seller_reward_debt = reward_accounts
+        .reward_debt
+        .then(|| find_reward_debt_address(&reward_center, &seller).0);
+    let buyer_reward_vesting = reward_accounts
+        .reward_vesting
+        .then(|| find_reward_vesting_address(&reward_center, &buyer).0);
+    let seller_reward_vesting = reward_accounts
+        .reward_vesting
+        .then(|| find_reward_vesting_address(&reward_center, &seller).0);
+    let buyer_reward_window = reward_accounts
+        .reward_window
+        .then(|| find_wallet_reward_window_address(&reward_center, &buyer).0);
+    let seller_reward_window = reward_accounts
+        .reward_window
+        .then(|| find_wallet_reward_window_address(&reward_center, &seller).0);
+    let (purchase_ticket, purchase_ticket_bump) =
+        find_sale_purchase_ticket_address(&reward_center, &seller, &buyer, &metadata, sale_number);
+    let (sale_counter, _) = find_sale_counter_address(&reward_center, &token_mint);
+    let sale_record = reward_accounts
+        .sale_record
+        .then(|| find_sale_record_address(&reward_center, &token_mint).0);
+    let attributor_reward_token_account = attributor
+        .map(|attributor_wallet| get_associated_token_address(&attributor_wallet, &reward_mint));
+    let attributor = attributor
+        .map(|attributor_wallet| find_attributor_address(&reward_center, &attributor_wallet).0);
+    let sponsor_pool = sponsored
+        .then(|| find_sponsor_pool_address(&reward_center, &collection.unwrap_or_default()).0);
+    let sponsor_pool_reward_token_account =
+        sponsor_pool.map(|sponsor_pool| get_associated_token_address(&sponsor_pool, &reward_mint));
 
     let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
         &buyer,
@@ -491,7 +1281,7 @@ pub fn buy_listing(
     let (program_as_signer, program_as_signer_bump) =
         mtly_auction_house::pda::find_program_as_signer_address();
 
-    let accounts = rewards_accounts::BuyListing {
+    let mut accounts = rewards_accounts::BuyListing {
         buyer,
         payment_account,
         transfer_authority,
@@ -511,6 +1301,21 @@ pub fn buy_listing(
         escrow_payment_account,
         reward_center,
         reward_center_reward_token_account,
+        rewardable_collection,
+        buyer_reward_debt,
+        seller_reward_debt,
+        buyer_reward_vesting,
+        seller_reward_vesting,
+        buyer_reward_window,
+        seller_reward_window,
+        purchase_ticket,
+        sale_counter,
+        sale_record,
+        attributor,
+        attributor_reward_token_account,
+        sponsor_pool,
+        sponsor_pool_reward_token_account,
+        collection_oracle,
         auction_house,
         auction_house_treasury,
         buyer_trade_state,
@@ -525,6 +1330,8 @@ pub fn buy_listing(
     }
     .to_account_metas(None);
 
+    sign_as_collection_oracle(&mut accounts, collection_oracle);
+
     let data = instruction::BuyListing {
         buy_listing_params: BuyListingParams {
             escrow_payment_bump,
@@ -532,6 +1339,9 @@ pub fn buy_listing(
             program_as_signer_bump,
             seller_trade_state_bump,
             buyer_trade_state_bump,
+            max_price,
+            offer_bump,
+            purchase_ticket_bump,
         },
     }
     .data();
@@ -559,12 +1369,20 @@ pub fn accept_offer(
     AcceptOfferData {
         token_size,
         price,
+        min_price,
         reward_mint,
+        collection,
+        attributor,
+        collection_oracle,
+        sponsored,
+        sale_number,
+        reward_accounts,
     }: AcceptOfferData,
     creators: Vec<AccountMeta>,
 ) -> Instruction {
     let (reward_center, _) = find_reward_center_address(&auction_house);
     let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
+    let (_, listing_bump) = find_listing_address(&seller, &metadata, &reward_center);
 
     let (auction_house_fee_account, _) =
         mtly_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
@@ -578,6 +1396,40 @@ pub fn accept_offer(
         get_associated_token_address(&reward_center, &reward_mint);
     let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
     let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+    let (rewardable_collection, _) =
+        find_rewardable_collection_address(&reward_center, &collection.unwrap_or_default());
+    let buyer_reward_debt = reward_accounts
+        .reward_debt
+        .then(|| find_reward_debt_address(&reward_center, &buyer).0);
+    let seller_reward_debt = reward_accounts
+        .reward_debt
+        .then(|| find_reward_debt_address(&reward_center, &seller).0);
+    let buyer_reward_vesting = reward_accounts
+        .reward_vesting
+        .then(|| find_reward_vesting_address(&reward_center, &buyer).0);
+    let seller_reward_vesting = reward_accounts
+        .reward_vesting
+        .then(|| find_reward_vesting_address(&reward_center, &seller).0);
+    let buyer_reward_window = reward_accounts
+        .reward_window
+        .then(|| find_wallet_reward_window_address(&reward_center, &buyer).0);
+    let seller_reward_window = reward_accounts
+        .reward_window
+        .then(|| find_wallet_reward_window_address(&reward_center, &seller).0);
+    let (purchase_ticket, purchase_ticket_bump) =
+        find_sale_purchase_ticket_address(&reward_center, &seller, &buyer, &metadata, sale_number);
+    let (sale_counter, _) = find_sale_counter_address(&reward_center, &token_mint);
+    let sale_record = reward_accounts
+        .sale_record
+        .then(|| find_sale_record_address(&reward_center, &token_mint).0);
+    let attributor_reward_token_account = attributor
+        .map(|attributor_wallet| get_associated_token_address(&attributor_wallet, &reward_mint));
+    let attributor = attributor
+        .map(|attributor_wallet| find_attributor_address(&reward_center, &attributor_wallet).0);
+    let sponsor_pool = sponsored
+        .then(|| find_sponsor_pool_address(&reward_center, &collection.unwrap_or_default()).0);
+    let sponsor_pool_reward_token_account =
+        sponsor_pool.map(|sponsor_pool| get_associated_token_address(&sponsor_pool, &reward_mint));
 
     let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
         &buyer,
@@ -610,7 +1462,7 @@ pub fn accept_offer(
     let (program_as_signer, program_as_signer_bump) =
         mtly_auction_house::pda::find_program_as_signer_address();
 
-    let accounts = rewards_accounts::AcceptOffer {
+    let mut accounts = rewards_accounts::AcceptOffer {
         buyer,
         buyer_reward_token_account,
         seller,
@@ -628,6 +1480,21 @@ pub fn accept_offer(
         escrow_payment_account,
         reward_center,
         reward_center_reward_token_account,
+        rewardable_collection,
+        buyer_reward_debt,
+        seller_reward_debt,
+        buyer_reward_vesting,
+        seller_reward_vesting,
+        buyer_reward_window,
+        seller_reward_window,
+        purchase_ticket,
+        sale_counter,
+        sale_record,
+        attributor,
+        attributor_reward_token_account,
+        sponsor_pool,
+        sponsor_pool_reward_token_account,
+        collection_oracle,
         auction_house,
         auction_house_treasury,
         buyer_trade_state,
@@ -642,6 +1509,8 @@ pub fn accept_offer(
     }
     .to_account_metas(None);
 
+    sign_as_collection_oracle(&mut accounts, collection_oracle);
+
     let data = instruction::AcceptOffer {
         accept_offer_params: AcceptOfferParams {
             escrow_payment_bump,
@@ -649,6 +1518,9 @@ pub fn accept_offer(
             program_as_signer_bump,
             seller_trade_state_bump,
             buyer_trade_state_bump,
+            min_price,
+            listing_bump,
+            purchase_ticket_bump,
         },
     }
     .data();
@@ -659,3 +1531,115 @@ pub fn accept_offer(
         data,
     }
 }
+
+/// Finds the purchase ticket recording the sale of an NFT between a seller and a buyer, keyed by
+/// their listing and offer addresses whether the sale was a listing purchase or an accepted offer.
+/// Marks the collection oracle as a signer of the sale so it can approve the rewards.
+fn sign_as_collection_oracle(accounts: &mut [AccountMeta], collection_oracle: Option<Pubkey>) {
+    if let Some(collection_oracle) = collection_oracle {
+        accounts
+            .iter_mut()
+            .filter(|account| account.pubkey == collection_oracle)
+            .for_each(|account| account.is_signer = true);
+    }
+}
+
+pub fn find_sale_purchase_ticket_address(
+    reward_center: &Pubkey,
+    seller: &Pubkey,
+    buyer: &Pubkey,
+    metadata: &Pubkey,
+    sale_number: u64,
+) -> (Pubkey, u8) {
+    let (listing, _) = find_listing_address(seller, metadata, reward_center);
+    let (offer, _) = find_offer_address(buyer, metadata, reward_center);
+
+    find_purchase_ticket_address(&listing, &offer, sale_number)
+}
+
+/// Addresses shared by the sales of an auction house, and of a collection when given, to put in an
+/// address lookup table so that a sale with its optional reward accounts fits in a versioned
+/// transaction.
+pub fn find_sale_lookup_table_addresses(
+    auction_house: &Pubkey,
+    authority: &Pubkey,
+    treasury_mint: &Pubkey,
+    reward_mint: &Pubkey,
+    collection: Option<&Pubkey>,
+) -> Vec<Pubkey> {
+    let (reward_center, _) = find_reward_center_address(auction_house);
+    let (auction_house_fee_account, _) =
+        mtly_auction_house::pda::find_auction_house_fee_account_address(auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(auction_house);
+    let (ah_auctioneer_pda, _) =
+        mtly_auction_house::pda::find_auctioneer_pda(auction_house, &reward_center);
+    let (program_as_signer, _) = mtly_auction_house::pda::find_program_as_signer_address();
+
+    let mut addresses = vec![
+        *auction_house,
+        *authority,
+        *treasury_mint,
+        auction_house_fee_account,
+        auction_house_treasury,
+        ah_auctioneer_pda,
+        reward_center,
+        get_associated_token_address(&reward_center, reward_mint),
+        program_as_signer,
+        mtly_auction_house::id(),
+        spl_associated_token_account::id(),
+        spl_token::id(),
+        system_program::id(),
+        sysvar::rent::id(),
+    ];
+
+    if let Some(collection) = collection {
+        let (sponsor_pool, _) = find_sponsor_pool_address(&reward_center, collection);
+
+        addresses.extend([
+            find_rewardable_collection_address(&reward_center, collection).0,
+            sponsor_pool,
+            get_associated_token_address(&sponsor_pool, reward_mint),
+        ]);
+    }
+
+    addresses
+}
+
+/// Fetches the purchase ticket recording a sale of an NFT between a seller and a buyer.
+pub fn fetch_purchase_ticket<C: Deref<Target = impl SolanaSigner> + Clone>(
+    program: &Program<C>,
+    reward_center: &Pubkey,
+    seller: &Pubkey,
+    buyer: &Pubkey,
+    metadata: &Pubkey,
+    sale_number: u64,
+) -> std::result::Result<PurchaseTicket, ClientError> {
+    let (purchase_ticket, _) =
+        find_sale_purchase_ticket_address(reward_center, seller, buyer, metadata, sale_number);
+
+    program.account(purchase_ticket)
+}
+
+/// Fetches the number of sales of an NFT so far, numbering the purchase ticket of its next sale.
+pub fn fetch_sale_count<C: Deref<Target = impl SolanaSigner> + Clone>(
+    program: &Program<C>,
+    reward_center: &Pubkey,
+    mint: &Pubkey,
+) -> std::result::Result<u64, ClientError> {
+    let (sale_counter, _) = find_sale_counter_address(reward_center, mint);
+
+    match program.account::<SaleCounter>(sale_counter) {
+        Ok(sale_counter) => Ok(sale_counter.sale_count),
+        Err(ClientError::AccountNotFound) => Ok(0),
+        Err(error) => Err(error),
+    }
+}
+
+/// Reads the number of sales of an NFT so far from the data of its sale counter, which only exists
+/// once the NFT was sold.
+pub fn read_sale_count(sale_counter_data: Option<&[u8]>) -> Result<u64> {
+    match sale_counter_data {
+        Some(mut data) => Ok(SaleCounter::try_deserialize(&mut data)?.sale_count),
+        None => Ok(0),
+    }
+}